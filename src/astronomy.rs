@@ -0,0 +1,833 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! This module implements the astronomical computations needed by the
+//! lunisolar calendars: positions of the Sun and the Moon, new moons,
+//! solar terms (including the equinoxes and solstices), sunrise and sunset.
+//!
+//! The algorithms are those of Jean Meeus, *Astronomical Algorithms* (2nd ed., 1998),
+//! truncated to an accuracy of about a minute of time for the years 1900 - 2150.
+//! Times are Julian days in Universal Time (UT).
+
+use std::f64::consts::PI;
+use time::{Date, Duration, OffsetDateTime, UtcOffset};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// The 24 solar terms of the Chinese calendar (jieqi), in the order they fall
+/// in a Gregorian year: the Sun reaches a multiple of 15 degrees of longitude.
+///
+/// The equinoxes and the solstices are four of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SolarTerm {
+    /// Minor Cold (Xiaohan, 285 degrees), around 6 January.
+    MinorCold,
+    /// Major Cold (Dahan, 300 degrees), around 20 January.
+    MajorCold,
+    /// Start of Spring (Lichun, 315 degrees), around 4 February.
+    StartOfSpring,
+    /// Rain Water (Yushui, 330 degrees), around 19 February.
+    RainWater,
+    /// Awakening of Insects (Jingzhe, 345 degrees), around 6 March.
+    AwakeningOfInsects,
+    /// March (vernal) equinox (Chunfen, 0 degrees), around 20 March.
+    MarchEquinox,
+    /// Pure Brightness (Qingming, 15 degrees), around 5 April.
+    PureBrightness,
+    /// Grain Rain (Guyu, 30 degrees), around 20 April.
+    GrainRain,
+    /// Start of Summer (Lixia, 45 degrees), around 6 May.
+    StartOfSummer,
+    /// Grain Buds (Xiaoman, 60 degrees), around 21 May.
+    GrainBuds,
+    /// Grain in Ear (Mangzhong, 75 degrees), around 6 June.
+    GrainInEar,
+    /// June solstice (Xiazhi, 90 degrees), around 21 June.
+    JuneSolstice,
+    /// Minor Heat (Xiaoshu, 105 degrees), around 7 July.
+    MinorHeat,
+    /// Major Heat (Dashu, 120 degrees), around 23 July.
+    MajorHeat,
+    /// Start of Autumn (Liqiu, 135 degrees), around 8 August.
+    StartOfAutumn,
+    /// End of Heat (Chushu, 150 degrees), around 23 August.
+    EndOfHeat,
+    /// White Dew (Bailu, 165 degrees), around 8 September.
+    WhiteDew,
+    /// September (autumnal) equinox (Qiufen, 180 degrees), around 23 September.
+    SeptemberEquinox,
+    /// Cold Dew (Hanlu, 195 degrees), around 8 October.
+    ColdDew,
+    /// Frost's Descent (Shuangjiang, 210 degrees), around 23 October.
+    FrostsDescent,
+    /// Start of Winter (Lidong, 225 degrees), around 7 November.
+    StartOfWinter,
+    /// Minor Snow (Xiaoxue, 240 degrees), around 22 November.
+    MinorSnow,
+    /// Major Snow (Daxue, 255 degrees), around 7 December.
+    MajorSnow,
+    /// December (winter) solstice (Dongzhi, 270 degrees), around 22 December.
+    DecemberSolstice,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Julian day of the J2000.0 epoch (1 January 2000, 12:00 TT).
+pub const J2000: f64 = 2_451_545.0;
+
+/// Mean length of the synodic month (days).
+pub const SYNODIC_MONTH: f64 = 29.530_588_861;
+
+/// Mean length of the tropical year (days).
+pub const TROPICAL_YEAR: f64 = 365.242_189;
+
+/// Altitude of the centre of the Sun at sunrise and sunset (degrees),
+/// accounting for refraction and the apparent radius of the Sun.
+const SUNRISE_ALTITUDE: f64 = -0.8333;
+
+/// Periodic terms L0 of the heliocentric longitude of the Earth (VSOP87, as truncated by Meeus, appendix III):
+/// amplitude (1e-8 radians), phase (radians) and frequency (radians per Julian millennium).
+#[rustfmt::skip]
+const EARTH_L0: [(f64, f64, f64); 64] = [
+    (175_347_046.0,       0.0,          0.0),
+    (  3_341_656.0, 4.6692568, 6283.0758500),
+    (     34_894.0,   4.62610,  12566.15170),
+    (      3_497.0,    2.7441,    5753.3849),
+    (      3_418.0,    2.8289,       3.5231),
+    (      3_136.0,    3.6277,   77713.7715),
+    (      2_676.0,    4.4181,    7860.4194),
+    (      2_343.0,    6.1352,    3930.2097),
+    (      1_324.0,    0.7425,   11506.7698),
+    (      1_273.0,    2.0371,     529.6910),
+    (      1_199.0,    1.1096,    1577.3435),
+    (        990.0,     5.233,     5884.927),
+    (        902.0,     2.045,       26.298),
+    (        857.0,     3.508,      398.149),
+    (        780.0,     1.179,     5223.694),
+    (        753.0,     2.533,     5507.553),
+    (        505.0,     4.583,    18849.228),
+    (        492.0,     4.205,      775.523),
+    (        357.0,     2.920,        0.067),
+    (        317.0,     5.849,    11790.629),
+    (        284.0,     1.899,      796.298),
+    (        271.0,     0.315,    10977.079),
+    (        243.0,     0.345,     5486.778),
+    (        206.0,     4.806,     2544.314),
+    (        205.0,     1.869,     5573.143),
+    (        202.0,     2.458,     6069.777),
+    (        156.0,     0.833,      213.299),
+    (        132.0,     3.411,     2942.463),
+    (        126.0,     1.083,       20.775),
+    (        115.0,     0.645,        0.980),
+    (        103.0,     0.636,     4694.003),
+    (        102.0,     0.976,    15720.839),
+    (        102.0,     4.267,        7.114),
+    (         99.0,      6.21,      2146.17),
+    (         98.0,      0.68,       155.42),
+    (         86.0,      5.98,    161000.69),
+    (         85.0,      1.30,      6275.96),
+    (         85.0,      3.67,     71430.70),
+    (         80.0,      1.81,     17260.15),
+    (         79.0,      3.04,     12036.46),
+    (         75.0,      1.76,      5088.63),
+    (         74.0,      3.50,      3154.69),
+    (         74.0,      4.68,       801.82),
+    (         70.0,      0.83,      9437.76),
+    (         62.0,      3.98,      8827.39),
+    (         61.0,      1.82,      7084.90),
+    (         57.0,      2.78,      6286.60),
+    (         56.0,      4.39,     14143.50),
+    (         56.0,      3.47,      6279.55),
+    (         52.0,      0.19,     12139.55),
+    (         52.0,      1.33,      1748.02),
+    (         51.0,      0.28,      5856.48),
+    (         49.0,      0.49,      1194.45),
+    (         41.0,      5.37,      8429.24),
+    (         41.0,      2.40,     19651.05),
+    (         39.0,      6.17,     10447.39),
+    (         37.0,      6.04,     10213.29),
+    (         37.0,      2.57,      1059.38),
+    (         36.0,      1.71,      2352.87),
+    (         36.0,      1.78,      6812.77),
+    (         33.0,      0.59,     17789.85),
+    (         30.0,      0.44,     83996.85),
+    (         30.0,      2.74,      1349.87),
+    (         25.0,      3.16,      4690.48),
+];
+
+/// Periodic terms L1 of the heliocentric longitude of the Earth (see [`EARTH_L0`]).
+#[rustfmt::skip]
+const EARTH_L1: [(f64, f64, f64); 34] = [
+    (628_331_966_747.0,      0.0,         0.0),
+    (        206_059.0, 2.678235, 6283.075850),
+    (          4_303.0,   2.6351,  12566.1517),
+    (            425.0,    1.590,       3.523),
+    (            119.0,    5.796,      26.298),
+    (            109.0,    2.966,    1577.344),
+    (             93.0,     2.59,    18849.23),
+    (             72.0,     1.14,      529.69),
+    (             68.0,     1.87,      398.15),
+    (             67.0,     4.41,     5507.55),
+    (             59.0,     2.89,     5223.69),
+    (             56.0,     2.17,      155.42),
+    (             45.0,     0.40,      796.30),
+    (             36.0,     0.47,      775.52),
+    (             29.0,     2.65,        7.11),
+    (             21.0,     5.34,        0.98),
+    (             19.0,     1.85,     5486.78),
+    (             19.0,     4.97,      213.30),
+    (             17.0,     2.99,     6275.96),
+    (             16.0,     0.03,     2544.31),
+    (             16.0,     1.43,     2146.17),
+    (             15.0,     1.21,    10977.08),
+    (             12.0,     2.83,     1748.02),
+    (             12.0,     3.26,     5088.63),
+    (             12.0,     5.27,     1194.45),
+    (             12.0,     2.08,     4694.00),
+    (             11.0,     0.77,      553.57),
+    (             10.0,     1.30,     6286.60),
+    (             10.0,     4.24,     1349.87),
+    (              9.0,     2.70,      242.73),
+    (              9.0,     5.64,      951.72),
+    (              8.0,     5.30,     2352.87),
+    (              6.0,     2.65,     9437.76),
+    (              6.0,     4.67,     4690.48),
+];
+
+/// Periodic terms L2 of the heliocentric longitude of the Earth (see [`EARTH_L0`]).
+#[rustfmt::skip]
+const EARTH_L2: [(f64, f64, f64); 20] = [
+    (52_919.0,    0.0,       0.0),
+    ( 8_720.0, 1.0721, 6283.0758),
+    (   309.0,  0.867, 12566.152),
+    (    27.0,   0.05,      3.52),
+    (    16.0,   5.19,     26.30),
+    (    16.0,   3.68,    155.42),
+    (    10.0,   0.76,  18849.23),
+    (     9.0,   2.06,  77713.77),
+    (     7.0,   0.83,    775.52),
+    (     5.0,   4.66,   1577.34),
+    (     4.0,   1.03,      7.11),
+    (     4.0,   3.44,   5573.14),
+    (     3.0,   5.14,    796.30),
+    (     3.0,   6.05,   5507.55),
+    (     3.0,   1.19,    242.73),
+    (     3.0,   6.12,    529.69),
+    (     3.0,   0.31,    398.15),
+    (     3.0,   2.28,    553.57),
+    (     2.0,   4.38,   5223.69),
+    (     2.0,   3.75,      0.98),
+];
+
+/// Periodic terms L3 of the heliocentric longitude of the Earth (see [`EARTH_L0`]).
+#[rustfmt::skip]
+const EARTH_L3: [(f64, f64, f64); 7] = [
+    (289.0, 5.844, 6283.076),
+    ( 35.0,   0.0,      0.0),
+    ( 17.0,  5.49, 12566.15),
+    (  3.0,  5.20,   155.42),
+    (  1.0,  4.72,     3.52),
+    (  1.0,  5.30, 18849.23),
+    (  1.0,  5.97,   242.73),
+];
+
+/// Periodic terms L4 of the heliocentric longitude of the Earth (see [`EARTH_L0`]).
+#[rustfmt::skip]
+const EARTH_L4: [(f64, f64, f64); 3] = [
+    (114.0,   PI,      0.0),
+    (  8.0, 4.13,  6283.08),
+    (  1.0, 3.84, 12566.15),
+];
+
+/// Periodic terms L5 of the heliocentric longitude of the Earth (see [`EARTH_L0`]).
+#[rustfmt::skip]
+const EARTH_L5: [(f64, f64, f64); 1] = [
+    (1.0, PI, 0.0),
+];
+
+/// Periodic terms R0 of the distance of the Earth from the Sun (1e-8 AU, see [`EARTH_L0`]), truncated to the main terms.
+#[rustfmt::skip]
+const EARTH_R0: [(f64, f64, f64); 6] = [
+    (100_013_989.0,       0.0,          0.0),
+    (  1_670_700.0, 3.0984635, 6283.0758500),
+    (     13_956.0,   3.05525,  12566.15170),
+    (      3_084.0,    5.1985,   77713.7715),
+    (      1_628.0,    1.1739,    5753.3849),
+    (      1_576.0,    2.8469,    7860.4194),
+];
+
+/// Periodic terms R1 of the distance of the Earth from the Sun (see [`EARTH_R0`]).
+#[rustfmt::skip]
+const EARTH_R1: [(f64, f64, f64); 2] = [
+    (103_019.0, 1.107490, 6283.075850),
+    (  1_721.0,   1.0644,  12566.1517),
+];
+
+/// Periodic terms of the longitude of the Moon (Meeus, table 47.A):
+/// multiples of D, M, M', F, and the coefficient (1e-6 degrees).
+#[rustfmt::skip]
+const MOON_LONGITUDE_TERMS: [(f64, f64, f64, f64, f64); 34] = [
+    (0.0,  0.0,  1.0,  0.0,  6_288_774.0),
+    (2.0,  0.0, -1.0,  0.0,  1_274_027.0),
+    (2.0,  0.0,  0.0,  0.0,    658_314.0),
+    (0.0,  0.0,  2.0,  0.0,    213_618.0),
+    (0.0,  1.0,  0.0,  0.0,   -185_116.0),
+    (0.0,  0.0,  0.0,  2.0,   -114_332.0),
+    (2.0,  0.0, -2.0,  0.0,     58_793.0),
+    (2.0, -1.0, -1.0,  0.0,     57_066.0),
+    (2.0,  0.0,  1.0,  0.0,     53_322.0),
+    (2.0, -1.0,  0.0,  0.0,     45_758.0),
+    (0.0,  1.0, -1.0,  0.0,    -40_923.0),
+    (1.0,  0.0,  0.0,  0.0,    -34_720.0),
+    (0.0,  1.0,  1.0,  0.0,    -30_383.0),
+    (2.0,  0.0,  0.0, -2.0,     15_327.0),
+    (0.0,  0.0,  1.0,  2.0,    -12_528.0),
+    (0.0,  0.0,  1.0, -2.0,     10_980.0),
+    (4.0,  0.0, -1.0,  0.0,     10_675.0),
+    (0.0,  0.0,  3.0,  0.0,     10_034.0),
+    (4.0,  0.0, -2.0,  0.0,      8_548.0),
+    (2.0,  1.0, -1.0,  0.0,     -7_888.0),
+    (2.0,  1.0,  0.0,  0.0,     -6_766.0),
+    (1.0,  0.0, -1.0,  0.0,     -5_163.0),
+    (1.0,  1.0,  0.0,  0.0,      4_987.0),
+    (2.0, -1.0,  1.0,  0.0,      4_036.0),
+    (2.0,  0.0,  2.0,  0.0,      3_994.0),
+    (4.0,  0.0,  0.0,  0.0,      3_861.0),
+    (2.0,  0.0, -3.0,  0.0,      3_665.0),
+    (0.0,  1.0, -2.0,  0.0,     -2_689.0),
+    (2.0,  0.0, -1.0,  2.0,     -2_602.0),
+    (2.0, -1.0, -2.0,  0.0,      2_390.0),
+    (1.0,  0.0,  1.0,  0.0,     -2_348.0),
+    (2.0, -2.0,  0.0,  0.0,      2_236.0),
+    (0.0,  1.0,  2.0,  0.0,     -2_120.0),
+    (0.0,  2.0,  0.0,  0.0,     -2_069.0),
+];
+
+/// Periodic terms of the time of the new moon (Meeus, chapter 49):
+/// multiples of M, M', F, Ω, the power of E, and the coefficient (days).
+#[rustfmt::skip]
+const NEW_MOON_TERMS: [(f64, f64, f64, f64, i32, f64); 25] = [
+    (0.0,  1.0,  0.0, 0.0, 0, -0.407_20),
+    (1.0,  0.0,  0.0, 0.0, 1,  0.172_41),
+    (0.0,  2.0,  0.0, 0.0, 0,  0.016_08),
+    (0.0,  0.0,  2.0, 0.0, 0,  0.010_39),
+    (-1.0, 1.0,  0.0, 0.0, 1,  0.007_39),
+    (1.0,  1.0,  0.0, 0.0, 1, -0.005_14),
+    (2.0,  0.0,  0.0, 0.0, 2,  0.002_08),
+    (0.0,  1.0, -2.0, 0.0, 0, -0.001_11),
+    (0.0,  1.0,  2.0, 0.0, 0, -0.000_57),
+    (1.0,  2.0,  0.0, 0.0, 1,  0.000_56),
+    (0.0,  3.0,  0.0, 0.0, 0, -0.000_42),
+    (1.0,  0.0,  2.0, 0.0, 1,  0.000_42),
+    (1.0,  0.0, -2.0, 0.0, 1,  0.000_38),
+    (-1.0, 2.0,  0.0, 0.0, 1, -0.000_24),
+    (0.0,  0.0,  0.0, 1.0, 0, -0.000_17),
+    (2.0,  1.0,  0.0, 0.0, 0, -0.000_07),
+    (0.0,  2.0, -2.0, 0.0, 0,  0.000_04),
+    (3.0,  0.0,  0.0, 0.0, 0,  0.000_04),
+    (1.0,  1.0, -2.0, 0.0, 0,  0.000_03),
+    (0.0,  2.0,  2.0, 0.0, 0,  0.000_03),
+    (1.0,  1.0,  2.0, 0.0, 0, -0.000_03),
+    (-1.0, 1.0,  2.0, 0.0, 0,  0.000_03),
+    (-1.0, 1.0, -2.0, 0.0, 0, -0.000_02),
+    (1.0,  3.0,  0.0, 0.0, 0, -0.000_02),
+    (0.0,  4.0,  0.0, 0.0, 0,  0.000_02),
+];
+
+/// Planetary arguments of the time of the new moon (Meeus, chapter 49):
+/// constant and rate (per lunation) of the argument (degrees), and the coefficient (days).
+#[rustfmt::skip]
+const NEW_MOON_PLANETARY_TERMS: [(f64, f64, f64); 13] = [
+    (251.88,  0.016_321, 0.000_165),
+    (251.83, 26.651_886, 0.000_164),
+    (349.42, 36.412_478, 0.000_126),
+    ( 84.66, 18.206_239, 0.000_110),
+    (141.74, 53.303_771, 0.000_062),
+    (207.14,  2.453_732, 0.000_060),
+    (154.84,  7.306_860, 0.000_056),
+    ( 34.52, 27.261_239, 0.000_047),
+    (207.19,  0.121_824, 0.000_042),
+    (291.34,  1.844_379, 0.000_040),
+    (161.72, 24.198_154, 0.000_037),
+    (239.56, 25.513_099, 0.000_035),
+    (331.55,  3.592_518, 0.000_023),
+];
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl SolarTerm {
+    /// Apparent longitude of the Sun at the solar term (degrees).
+    pub fn longitude(&self) -> f64 {
+        (285.0 + 15.0 * *self as i32 as f64) % 360.0
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to get the Julian day of a date and time.
+pub fn julian_day(datetime: OffsetDateTime) -> f64 {
+    let datetime = datetime.to_offset(time::UtcOffset::UTC);
+    let seconds = datetime.time().as_hms_nano();
+    let day_fraction = (seconds.0 as f64 * 3600.0
+        + seconds.1 as f64 * 60.0
+        + seconds.2 as f64
+        + seconds.3 as f64 * 1e-9)
+        / 86_400.0;
+
+    datetime.date().to_julian_day() as f64 - 0.5 + day_fraction
+}
+
+/// Function to get the (UTC) date and time of a Julian day.
+pub fn from_julian_day(jd: f64) -> OffsetDateTime {
+    let day = (jd + 0.5).floor();
+    let midnight = Date::from_julian_day(day as i32)
+        .expect("Julian day out of range")
+        .midnight()
+        .assume_utc();
+
+    midnight + Duration::seconds_f64((jd + 0.5 - day) * 86_400.0)
+}
+
+/// Function to get ΔT = TT - UT (seconds), for a (fractional) year,
+/// using the polynomial expressions of Espenak and Meeus.
+pub fn delta_t(year: f64) -> f64 {
+    match year {
+        y if y < 1900.0 => {
+            let t = y - 1860.0;
+            7.62 + 0.5737 * t - 0.251_754 * t.powi(2) + 0.016_806_68 * t.powi(3)
+                - 0.000_447_362_4 * t.powi(4)
+                + t.powi(5) / 233_174.0
+        }
+        y if y < 1920.0 => {
+            let t = y - 1900.0;
+            -2.79 + 1.494_119 * t - 0.059_893_9 * t.powi(2) + 0.006_196_6 * t.powi(3)
+                - 0.000_197 * t.powi(4)
+        }
+        y if y < 1941.0 => {
+            let t = y - 1920.0;
+            21.20 + 0.844_93 * t - 0.076_100 * t.powi(2) + 0.002_093_6 * t.powi(3)
+        }
+        y if y < 1961.0 => {
+            let t = y - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        }
+        y if y < 1986.0 => {
+            let t = y - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        }
+        y if y < 2005.0 => {
+            let t = y - 2000.0;
+            63.86 + 0.3345 * t - 0.060_374 * t.powi(2)
+                + 0.001_727_5 * t.powi(3)
+                + 0.000_651_814 * t.powi(4)
+                + 0.000_023_735_99 * t.powi(5)
+        }
+        y if y < 2050.0 => {
+            let t = y - 2000.0;
+            62.92 + 0.322_17 * t + 0.005_589 * t.powi(2)
+        }
+        y if y < 2150.0 => -20.0 + 32.0 * ((y - 1820.0) / 100.0).powi(2) - 0.5628 * (2150.0 - y),
+        y => -20.0 + 32.0 * ((y - 1820.0) / 100.0).powi(2),
+    }
+}
+
+/// Function to convert a Julian day in UT to a Julian ephemeris day (TT).
+fn ephemeris_day(jd: f64) -> f64 {
+    jd + delta_t(2000.0 + (jd - J2000) / 365.25) / 86_400.0
+}
+
+/// Function to get the nutation in longitude (degrees), to the four main terms.
+fn nutation_in_longitude(t: f64) -> f64 {
+    let omega = (125.044_52 - 1_934.136_261 * t).to_radians();
+    let sun = (280.466_5 + 36_000.769_8 * t).to_radians();
+    let moon = (218.316_5 + 481_267.881_3 * t).to_radians();
+
+    (-17.20 * omega.sin() - 1.32 * (2.0 * sun).sin() - 0.23 * (2.0 * moon).sin()
+        + 0.21 * (2.0 * omega).sin())
+        / 3600.0
+}
+
+/// Function to sum a VSOP87 series (terms L0, L1, ... of powers of `tau`).
+fn vsop87(series: &[&[(f64, f64, f64)]], tau: f64) -> f64 {
+    series.iter().rev().fold(0.0, |sum, terms| {
+        let terms: f64 = terms.iter().map(|&(a, b, c)| a * (b + c * tau).cos()).sum();
+        sum * tau + terms
+    }) / 1e8
+}
+
+/// Function to get the apparent geocentric longitude of the Sun (degrees, 0 - 360),
+/// accurate to about 1 arcsecond, i.e. half a minute of time (Meeus, chapter 25).
+pub fn sun_longitude(jd: f64) -> f64 {
+    let tau = (ephemeris_day(jd) - J2000) / 365_250.0;
+
+    let longitude = vsop87(
+        &[
+            &EARTH_L0, &EARTH_L1, &EARTH_L2, &EARTH_L3, &EARTH_L4, &EARTH_L5,
+        ],
+        tau,
+    );
+    let distance = vsop87(&[&EARTH_R0, &EARTH_R1], tau);
+
+    // Geocentric, in the FK5 system, with nutation and aberration
+    (longitude.to_degrees() + 180.0 - 0.090_33 / 3600.0 + nutation_in_longitude(10.0 * tau)
+        - 20.4898 / 3600.0 / distance)
+        .rem_euclid(360.0)
+}
+
+/// Function to get the apparent geocentric longitude of the Moon (degrees, 0 - 360),
+/// accurate to about 0.005 degrees (Meeus, chapter 47).
+pub fn moon_longitude(jd: f64) -> f64 {
+    let t = (ephemeris_day(jd) - J2000) / 36_525.0;
+
+    let l = 218.316_447_7 + 481_267.881_234_21 * t - 0.001_578_6 * t * t;
+    let d = 297.850_192_1 + 445_267.111_403_4 * t - 0.001_881_9 * t * t;
+    let m = 357.529_109_2 + 35_999.050_290_9 * t - 0.000_153_6 * t * t;
+    let mp = 134.963_396_4 + 477_198.867_505_5 * t + 0.008_741_4 * t * t;
+    let f = 93.272_095_0 + 483_202.017_523_3 * t - 0.003_653_9 * t * t;
+    let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t * t;
+
+    let a1 = 119.75 + 131.849 * t;
+    let a2 = 53.09 + 479_264.290 * t;
+
+    let periodic: f64 = MOON_LONGITUDE_TERMS
+        .iter()
+        .map(|&(cd, cm, cmp, cf, coefficient)| {
+            let argument = (cd * d + cm * m + cmp * mp + cf * f).to_radians();
+            coefficient * e.powi(cm.abs() as i32) * argument.sin()
+        })
+        .sum::<f64>()
+        + 3958.0 * a1.to_radians().sin()
+        + 1962.0 * (l - f).to_radians().sin()
+        + 318.0 * a2.to_radians().sin();
+
+    (l + periodic / 1e6 + nutation_in_longitude(t)).rem_euclid(360.0)
+}
+
+/// Function to get the elongation of the Moon from the Sun (degrees, 0 - 360),
+/// i.e. the difference of their longitudes: 0 at new moon, 180 at full moon.
+pub fn lunar_phase(jd: f64) -> f64 {
+    (moon_longitude(jd) - sun_longitude(jd)).rem_euclid(360.0)
+}
+
+/// Function to get the Julian day of the `k`-th new moon after (or before, if negative)
+/// the new moon of 6 January 2000 (Meeus, chapter 49).
+pub fn new_moon(k: i32) -> f64 {
+    let k = k as f64;
+    let t = k / 1236.85;
+
+    let jde = 2_451_550.097_66 + SYNODIC_MONTH * k + 0.000_154_37 * t.powi(2)
+        - 0.000_000_150 * t.powi(3)
+        + 0.000_000_000_73 * t.powi(4);
+
+    let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t * t;
+    let m = 2.5534 + 29.105_356_70 * k - 0.000_001_4 * t.powi(2) - 0.000_000_11 * t.powi(3);
+    let mp = 201.5643 + 385.816_935_28 * k + 0.010_758_2 * t.powi(2) + 0.000_012_38 * t.powi(3)
+        - 0.000_000_058 * t.powi(4);
+    let f = 160.7108 + 390.670_502_84 * k - 0.001_611_8 * t.powi(2) - 0.000_002_27 * t.powi(3)
+        + 0.000_000_011 * t.powi(4);
+    let omega = 124.7746 - 1.563_755_88 * k + 0.002_067_2 * t.powi(2) + 0.000_002_15 * t.powi(3);
+
+    let periodic: f64 = NEW_MOON_TERMS
+        .iter()
+        .map(|&(cm, cmp, cf, co, power, coefficient)| {
+            let argument = (cm * m + cmp * mp + cf * f + co * omega).to_radians();
+            coefficient * e.powi(power) * argument.sin()
+        })
+        .sum();
+
+    let planetary: f64 = NEW_MOON_PLANETARY_TERMS
+        .iter()
+        .map(|&(constant, rate, coefficient)| {
+            coefficient * (constant + rate * k).to_radians().sin()
+        })
+        .sum::<f64>()
+        + 0.000_325
+            * (299.77 + 0.107_408 * k - 0.009_173 * t * t)
+                .to_radians()
+                .sin();
+
+    let jde = jde + periodic + planetary;
+
+    // Back to UT
+    jde - delta_t(2000.0 + (jde - J2000) / 365.25) / 86_400.0
+}
+
+/// Function to get the lunation number (see [`new_moon`]) of the last new moon at or before a Julian day.
+pub fn lunation(jd: f64) -> i32 {
+    let mut k = ((jd - 2_451_550.097_66) / SYNODIC_MONTH).floor() as i32;
+
+    while new_moon(k) > jd {
+        k -= 1;
+    }
+    while new_moon(k + 1) <= jd {
+        k += 1;
+    }
+
+    k
+}
+
+/// Function to get the Julian day (UT) at which the Sun reaches a solar term in a year.
+///
+/// ```
+/// use time::macros::{date, offset};
+/// use calenda_rs::astronomy::{solar_term, solar_term_date, SolarTerm};
+///
+/// // The March equinox of 2024 fell at 03:06 UTC, on 20 March
+/// let equinox = solar_term(2024, SolarTerm::MarchEquinox);
+/// assert!((equinox - 2_460_389.629).abs() < 0.001);
+///
+/// // The June solstice of 2024 fell on 20 June in UTC, but on 21 June in Tokyo
+/// assert_eq!(solar_term_date(2024, SolarTerm::JuneSolstice, offset!(UTC)), date!(2024 - 06 - 20));
+/// assert_eq!(solar_term_date(2024, SolarTerm::JuneSolstice, offset!(+9)), date!(2024 - 06 - 21));
+/// ```
+pub fn solar_term(year: i32, term: SolarTerm) -> f64 {
+    let longitude = term.longitude();
+    let new_year = Date::from_ordinal_date(year, 1).expect("year out of range");
+
+    // Minor Cold falls around 5.5 January
+    let mut jd = new_year.to_julian_day() as f64 + 5.0 + term as i32 as f64 * TROPICAL_YEAR / 24.0;
+
+    for _ in 0..50 {
+        let difference = (sun_longitude(jd) - longitude + 180.0).rem_euclid(360.0) - 180.0;
+        jd -= difference * TROPICAL_YEAR / 360.0;
+
+        if difference.abs() < 1e-7 {
+            break;
+        }
+    }
+
+    jd
+}
+
+/// Function to get the date of a solar term in a year, in a time zone (UTC offset).
+pub fn solar_term_date(year: i32, term: SolarTerm, offset: UtcOffset) -> Date {
+    from_julian_day(solar_term(year, term))
+        .to_offset(offset)
+        .date()
+}
+
+/// Function to get the altitude of the centre of the Sun (degrees, without refraction)
+/// at a location (degrees, east longitudes positive).
+fn sun_altitude(jd: f64, latitude: f64, longitude: f64) -> f64 {
+    let t = (ephemeris_day(jd) - J2000) / 36_525.0;
+    let lambda = sun_longitude(jd).to_radians();
+    let epsilon = (23.439_291_1 - 0.013_004_2 * t).to_radians();
+
+    let right_ascension = (lambda.sin() * epsilon.cos()).atan2(lambda.cos());
+    let declination = (epsilon.sin() * lambda.sin()).asin();
+
+    let sidereal_time = 280.460_618_37 + 360.985_647_366_29 * (jd - J2000);
+    let hour_angle = (sidereal_time + longitude).to_radians() - right_ascension;
+    let latitude = latitude.to_radians();
+
+    (latitude.sin() * declination.sin() + latitude.cos() * declination.cos() * hour_angle.cos())
+        .asin()
+        .to_degrees()
+}
+
+/// Function to find the root of `f` between `a` and `b` by bisection (`None` if there is no sign change).
+fn bisect(f: impl Fn(f64) -> f64, mut a: f64, mut b: f64) -> Option<f64> {
+    let mut fa = f(a);
+
+    if fa.signum() == f(b).signum() {
+        return None;
+    }
+
+    // About a tenth of a second
+    while b - a > 1e-6 {
+        let mid = 0.5 * (a + b);
+        let fm = f(mid);
+
+        if fm.signum() == fa.signum() {
+            (a, fa) = (mid, fm);
+        } else {
+            b = mid;
+        }
+    }
+
+    Some(0.5 * (a + b))
+}
+
+/// Function to get the Julian day of sunrise on a date, at a location
+/// (degrees, east longitudes positive), or `None` if the Sun does not rise.
+///
+/// The date is the local date at the location (by local mean time).
+pub fn sunrise(date: Date, latitude: f64, longitude: f64) -> Option<f64> {
+    let noon = date.to_julian_day() as f64 - longitude / 360.0;
+
+    bisect(
+        |jd| sun_altitude(jd, latitude, longitude) - SUNRISE_ALTITUDE,
+        noon - 0.5,
+        noon,
+    )
+}
+
+/// Function to get the Julian day of sunset on a date, at a location
+/// (degrees, east longitudes positive), or `None` if the Sun does not set.
+///
+/// The date is the local date at the location (by local mean time).
+pub fn sunset(date: Date, latitude: f64, longitude: f64) -> Option<f64> {
+    let noon = date.to_julian_day() as f64 - longitude / 360.0;
+
+    bisect(
+        |jd| sun_altitude(jd, latitude, longitude) - SUNRISE_ALTITUDE,
+        noon,
+        noon + 0.5,
+    )
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_astronomy {
+    use super::*;
+    use time::macros::{date, datetime, offset};
+    use time::Month;
+
+    // Julian day to date and time, to the nearest minute.
+    fn minutes(jd: f64) -> OffsetDateTime {
+        let datetime = from_julian_day(jd + 30.0 / 86_400.0);
+        datetime
+            .replace_second(0)
+            .unwrap()
+            .replace_nanosecond(0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_julian_day() {
+        assert_eq!(julian_day(datetime!(2000-01-01 12:00 UTC)), J2000);
+        assert!((julian_day(datetime!(1987-04-10 19:21 +02:00)) - 2_446_896.223).abs() < 1e-3);
+        assert_eq!(minutes(2_446_896.306_25), datetime!(1987-04-10 19:21 UTC));
+    }
+
+    #[test]
+    fn test_positions() {
+        // Meeus, examples 25.b and 47.a
+        let sun = sun_longitude(julian_day(datetime!(1992-10-13 00:00 UTC)) - 59.0 / 86_400.0);
+        assert!((sun - 199.906_060).abs() < 0.000_1);
+
+        let moon = moon_longitude(julian_day(datetime!(1992-04-12 00:00 UTC)) - 59.0 / 86_400.0);
+        assert!((moon - 133.167).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_new_moon() {
+        // Meeus, example 49.a: 1977-02-18 03:37:42 TD
+        assert_eq!(minutes(new_moon(-283)), datetime!(1977-02-18 03:37 UTC));
+
+        assert_eq!(minutes(new_moon(0)), datetime!(2000-01-06 18:14 UTC));
+        assert_eq!(minutes(new_moon(300)), datetime!(2024-04-08 18:21 UTC));
+
+        let jd = julian_day(datetime!(2024-04-09 00:00 UTC));
+        assert_eq!(lunation(jd), 300);
+        assert_eq!(lunation(new_moon(300)), 300);
+
+        // The Moon and the Sun are in conjunction at new moon
+        let phase = lunar_phase(new_moon(300));
+        assert!(!(0.01..=359.99).contains(&phase));
+    }
+
+    #[test]
+    fn test_solar_terms() {
+        use SolarTerm::*;
+
+        // Published times, to the minute
+        let published = [
+            (2000, MarchEquinox, datetime!(2000-03-20 07:35 UTC)),
+            (2000, DecemberSolstice, datetime!(2000-12-21 13:37 UTC)),
+            (2024, JuneSolstice, datetime!(2024-06-20 20:51 UTC)),
+            (2024, SeptemberEquinox, datetime!(2024-09-22 12:44 UTC)),
+            (2024, PureBrightness, datetime!(2024-04-04 07:02 UTC)),
+            (1900, MarchEquinox, datetime!(1900-03-21 01:39 UTC)),
+        ];
+
+        for (year, term, datetime) in published {
+            assert!((solar_term(year, term) - julian_day(datetime)).abs() < 1.0 / 1440.0);
+        }
+
+        // Qingming, in Beijing time
+        assert_eq!(
+            solar_term_date(2024, PureBrightness, offset!(+8)),
+            date!(2024 - 04 - 04)
+        );
+        assert_eq!(
+            solar_term_date(2025, PureBrightness, offset!(+8)),
+            date!(2025 - 04 - 04)
+        );
+
+        // The terms fall in order, half a month apart, over the years covered
+        for year in [1900, 1999, 2000, 2100, 2150] {
+            let terms = [
+                MinorCold,
+                MajorCold,
+                StartOfSpring,
+                RainWater,
+                AwakeningOfInsects,
+                MarchEquinox,
+                PureBrightness,
+                GrainRain,
+                StartOfSummer,
+                GrainBuds,
+                GrainInEar,
+                JuneSolstice,
+                MinorHeat,
+                MajorHeat,
+                StartOfAutumn,
+                EndOfHeat,
+                WhiteDew,
+                SeptemberEquinox,
+                ColdDew,
+                FrostsDescent,
+                StartOfWinter,
+                MinorSnow,
+                MajorSnow,
+                DecemberSolstice,
+            ];
+
+            for (term, next) in terms.iter().zip(terms.iter().skip(1)) {
+                let interval = solar_term(year, *next) - solar_term(year, *term);
+                assert!((14.5..16.0).contains(&interval));
+            }
+
+            assert_eq!(
+                solar_term_date(year, MinorCold, offset!(UTC)).month(),
+                Month::January
+            );
+            assert_eq!(
+                solar_term_date(year, DecemberSolstice, offset!(UTC)).month(),
+                Month::December
+            );
+        }
+    }
+
+    #[test]
+    fn test_sunrise_sunset() {
+        // London, 2024-06-21: sunrise 03:43 UTC, sunset 20:21 UTC
+        let rise = sunrise(date!(2024 - 06 - 21), 51.5074, -0.1278).unwrap();
+        let set = sunset(date!(2024 - 06 - 21), 51.5074, -0.1278).unwrap();
+        assert!((rise - julian_day(datetime!(2024-06-21 03:43 UTC))).abs() < 1.0 / 1440.0);
+        assert!((set - julian_day(datetime!(2024-06-21 20:21 UTC))).abs() < 1.0 / 1440.0);
+
+        // No sunrise above the Arctic circle in December
+        assert_eq!(sunrise(date!(2024 - 12 - 21), 78.2, 15.6), None);
+    }
+}
@@ -12,7 +12,7 @@
 //! This module defines a `Calendar` type and its methods.
 
 use crate::utilities::is_weekend;
-use time::Date;
+use time::{Date, Time};
 
 /// Calendar trait.
 pub trait Calendar {
@@ -24,10 +24,24 @@ pub trait Calendar {
     /// This is the primary method to implement for a calendar.
     fn is_holiday(&self, date: Date) -> bool;
 
+    /// Check if the date is a weekend.
+    /// Saturday and Sunday by default; override for other working weeks.
+    fn is_weekend(&self, date: Date) -> bool {
+        is_weekend(date)
+    }
+
+    /// Check if the date is a weekend day which is a working day,
+    /// such as the make-up days for bridge holidays in China or Taiwan.
+    /// None by default.
+    fn is_working_weekend(&self, _date: Date) -> bool {
+        false
+    }
+
     /// Check if the date is a business day.
-    /// A business day is a day that is not a holiday and not a weekend.
+    /// A business day is a day that is not a holiday, and not a weekend
+    /// (unless it is a working weekend day).
     fn is_business_day(&self, date: Date) -> bool {
-        !is_weekend(date) && !self.is_holiday(date)
+        (!self.is_weekend(date) || self.is_working_weekend(date)) && !self.is_holiday(date)
     }
 
     /// Function to list all holidays for a given range of `Date`s.
@@ -68,3 +82,44 @@ pub trait Calendar {
         business_days
     }
 }
+
+/// Early close trait, for calendars with shortened trading sessions.
+///
+/// Exchange calendars (e.g. NYSE) close early on some business days,
+/// such as the day after Thanksgiving. The early close (and late open)
+/// times are given in the local time of the exchange.
+pub trait EarlyClose: Calendar {
+    /// Returns the early close time if the date is an early close day.
+    fn early_close(&self, date: Date) -> Option<Time>;
+
+    /// Returns the delayed opening time if trading starts late on the date
+    /// (e.g. a morning session cancelled for bad weather).
+    fn late_open(&self, _date: Date) -> Option<Time> {
+        None
+    }
+
+    /// Check if the date is an early close day.
+    /// Holidays and weekends are never early close days.
+    fn is_early_close(&self, date: Date) -> bool {
+        self.is_business_day(date) && self.early_close(date).is_some()
+    }
+
+    /// Function to list all early close days for a given range of `Date`s.
+    fn all_early_closes_between(&self, start_date: Date, end_date: Date) -> Vec<(Date, Time)> {
+        let mut early_closes = Vec::new();
+
+        let mut temp_date = start_date;
+
+        while temp_date <= end_date {
+            if self.is_business_day(temp_date) {
+                if let Some(close) = self.early_close(temp_date) {
+                    early_closes.push((temp_date, close));
+                }
+            }
+
+            temp_date = temp_date.next_day().unwrap();
+        }
+
+        early_closes
+    }
+}
@@ -0,0 +1,342 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! This module defines the Chinese lunisolar calendar (农历),
+//! and the dates of the lunar festivals, for the lunar years 1900 - 2100.
+
+use time::macros::date;
+use time::{Date, Duration};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// A date in the Chinese lunisolar calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LunarDate {
+    /// Lunar year (the Gregorian year in which it starts).
+    pub year: i32,
+
+    /// Lunar month (1 - 12).
+    pub month: u8,
+
+    /// Whether the month is a leap (intercalary) month.
+    pub is_leap_month: bool,
+
+    /// Day of the lunar month (1 - 30).
+    pub day: u8,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// First lunar year covered by the calendar.
+pub const LUNAR_MIN_YEAR: i32 = 1900;
+
+/// Last lunar year covered by the calendar.
+pub const LUNAR_MAX_YEAR: i32 = 2100;
+
+/// Gregorian date of the first day of the lunar year 1900.
+const LUNAR_EPOCH: Date = date!(1900 - 01 - 31);
+
+/// Compressed month lengths of the lunar years 1900 - 2100
+/// (the standard table, based on the Hong Kong Observatory data).
+///
+/// - bits 0 - 3: leap month (0 if none),
+/// - bits 4 - 15: months 12 to 1, 30 days if set (29 otherwise),
+/// - bit 16: leap month has 30 days if set (29 otherwise).
+#[rustfmt::skip]
+const LUNAR_INFO: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0, 0x055d2, // 1900 - 1909
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977, // 1910 - 1919
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970, // 1920 - 1929
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950, // 1930 - 1939
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557, // 1940 - 1949
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0, // 1950 - 1959
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0, // 1960 - 1969
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6, // 1970 - 1979
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570, // 1980 - 1989
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0, // 1990 - 1999
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5, // 2000 - 2009
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930, // 2010 - 2019
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530, // 2020 - 2029
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45, // 2030 - 2039
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0, // 2040 - 2049
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4, 0x0aae0, // 2050 - 2059
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4, // 2060 - 2069
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0, // 2070 - 2079
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160, // 2080 - 2089
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252, // 2090 - 2099
+    0x0d520, // 2100 - 2100
+];
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl LunarDate {
+    /// Create a new lunar date, if it exists in the calendar.
+    pub fn new(year: i32, month: u8, day: u8, is_leap_month: bool) -> Option<Self> {
+        let days = lunar_month_days(year, month, is_leap_month)?;
+
+        match (1..=days).contains(&day) {
+            true => Some(Self {
+                year,
+                month,
+                is_leap_month,
+                day,
+            }),
+            false => None,
+        }
+    }
+
+    /// Convert a Gregorian date to a lunar date.
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::chinese_calendar::LunarDate;
+    ///
+    /// let lunar = LunarDate::from_gregorian(date!(2024 - 02 - 10)).unwrap();
+    ///
+    /// assert_eq!((lunar.year, lunar.month, lunar.day), (2024, 1, 1));
+    /// ```
+    pub fn from_gregorian(date: Date) -> Option<Self> {
+        let mut offset = (date - LUNAR_EPOCH).whole_days();
+
+        if offset < 0 {
+            return None;
+        }
+
+        for year in LUNAR_MIN_YEAR..=LUNAR_MAX_YEAR {
+            let year_days = lunar_year_days(year) as i64;
+
+            if offset >= year_days {
+                offset -= year_days;
+                continue;
+            }
+
+            for (month, is_leap_month) in lunar_months(year) {
+                let month_days = lunar_month_days(year, month, is_leap_month).unwrap() as i64;
+
+                if offset < month_days {
+                    return Some(Self {
+                        year,
+                        month,
+                        is_leap_month,
+                        day: offset as u8 + 1,
+                    });
+                }
+
+                offset -= month_days;
+            }
+        }
+
+        None
+    }
+
+    /// Convert the lunar date to a Gregorian date.
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::chinese_calendar::LunarDate;
+    ///
+    /// // Mid-Autumn Festival, 2024
+    /// let lunar = LunarDate::new(2024, 8, 15, false).unwrap();
+    ///
+    /// assert_eq!(lunar.to_gregorian(), date!(2024 - 09 - 17));
+    /// ```
+    pub fn to_gregorian(&self) -> Date {
+        let mut days: i64 = (LUNAR_MIN_YEAR..self.year)
+            .map(|year| lunar_year_days(year) as i64)
+            .sum();
+
+        for (month, is_leap_month) in lunar_months(self.year) {
+            if (month, is_leap_month) == (self.month, self.is_leap_month) {
+                break;
+            }
+
+            days += lunar_month_days(self.year, month, is_leap_month).unwrap() as i64;
+        }
+
+        LUNAR_EPOCH + Duration::days(days + self.day as i64 - 1)
+    }
+}
+
+/// Function to get the leap month of a lunar year (`None` if there is no leap month).
+pub fn lunar_leap_month(year: i32) -> Option<u8> {
+    let info = lunar_info(year)?;
+
+    match (info & 0xf) as u8 {
+        0 => None,
+        month => Some(month),
+    }
+}
+
+/// Function to get the number of days (29 or 30) in a lunar month.
+pub fn lunar_month_days(year: i32, month: u8, is_leap_month: bool) -> Option<u8> {
+    let info = lunar_info(year)?;
+
+    if !(1..=12).contains(&month) || (is_leap_month && lunar_leap_month(year) != Some(month)) {
+        return None;
+    }
+
+    let is_long = match is_leap_month {
+        true => info & 0x10000 != 0,
+        false => info & (0x10000 >> month) != 0,
+    };
+
+    Some(if is_long { 30 } else { 29 })
+}
+
+/// Function to get the number of days in a lunar year.
+fn lunar_year_days(year: i32) -> u16 {
+    let info = lunar_info(year).unwrap();
+
+    let leap_month_days = match lunar_leap_month(year) {
+        Some(month) => lunar_month_days(year, month, true).unwrap() as u16,
+        None => 0,
+    };
+
+    // 12 months of 29 days, plus one day for each 30-day month
+    12 * 29 + (info & 0xfff0).count_ones() as u16 + leap_month_days
+}
+
+/// Function to iterate over the (month, is_leap_month) pairs of a lunar year, in order.
+fn lunar_months(year: i32) -> impl Iterator<Item = (u8, bool)> {
+    let leap_month = lunar_leap_month(year);
+
+    (1..=12).flat_map(move |month| {
+        let leap = (leap_month == Some(month)).then_some((month, true));
+
+        std::iter::once((month, false)).chain(leap)
+    })
+}
+
+/// Function to get the compressed data of a lunar year.
+fn lunar_info(year: i32) -> Option<u32> {
+    match (LUNAR_MIN_YEAR..=LUNAR_MAX_YEAR).contains(&year) {
+        true => Some(LUNAR_INFO[(year - LUNAR_MIN_YEAR) as usize]),
+        false => None,
+    }
+}
+
+/// Function to get the Gregorian date of a day of a (non-leap) lunar month.
+fn lunar_festival(year: i32, month: u8, day: u8) -> Option<Date> {
+    LunarDate::new(year, month, day, false).map(|lunar| lunar.to_gregorian())
+}
+
+/// Chinese (Lunar) New Year, the first day of the first lunar month.
+///
+/// ```
+/// use time::macros::date;
+/// use calenda_rs::chinese_calendar::chinese_new_year;
+///
+/// assert_eq!(chinese_new_year(2025), Some(date!(2025 - 01 - 29)));
+/// ```
+pub fn chinese_new_year(year: i32) -> Option<Date> {
+    lunar_festival(year, 1, 1)
+}
+
+/// Lantern Festival (元宵节), the 15th day of the first lunar month.
+pub fn lantern_festival(year: i32) -> Option<Date> {
+    lunar_festival(year, 1, 15)
+}
+
+/// Buddha's Birthday (佛誕), the 8th day of the fourth lunar month.
+pub fn buddhas_birthday(year: i32) -> Option<Date> {
+    lunar_festival(year, 4, 8)
+}
+
+/// Dragon Boat (Tuen Ng) Festival (端午节), the 5th day of the fifth lunar month.
+pub fn dragon_boat_festival(year: i32) -> Option<Date> {
+    lunar_festival(year, 5, 5)
+}
+
+/// Mid-Autumn Festival (中秋节), the 15th day of the eighth lunar month.
+pub fn mid_autumn_festival(year: i32) -> Option<Date> {
+    lunar_festival(year, 8, 15)
+}
+
+/// Double Ninth (Chung Yeung) Festival (重阳节), the 9th day of the ninth lunar month.
+pub fn double_ninth_festival(year: i32) -> Option<Date> {
+    lunar_festival(year, 9, 9)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_chinese_calendar {
+    use super::*;
+    use crate::utilities::date_sequence;
+
+    #[test]
+    fn test_chinese_new_year() {
+        assert_eq!(chinese_new_year(1900), Some(date!(1900 - 01 - 31)));
+        assert_eq!(chinese_new_year(1949), Some(date!(1949 - 01 - 29)));
+        assert_eq!(chinese_new_year(2000), Some(date!(2000 - 02 - 05)));
+        assert_eq!(chinese_new_year(2020), Some(date!(2020 - 01 - 25)));
+        assert_eq!(chinese_new_year(2023), Some(date!(2023 - 01 - 22)));
+        assert_eq!(chinese_new_year(2024), Some(date!(2024 - 02 - 10)));
+        assert_eq!(chinese_new_year(2050), Some(date!(2050 - 01 - 23)));
+        assert_eq!(chinese_new_year(2100), Some(date!(2100 - 02 - 09)));
+        assert_eq!(chinese_new_year(1899), None);
+        assert_eq!(chinese_new_year(2101), None);
+    }
+
+    #[test]
+    fn test_leap_months() {
+        assert_eq!(lunar_leap_month(2020), Some(4));
+        assert_eq!(lunar_leap_month(2023), Some(2));
+        assert_eq!(lunar_leap_month(2024), None);
+        assert_eq!(lunar_leap_month(2033), Some(11));
+
+        assert_eq!(lunar_month_days(2023, 2, true), Some(29));
+        assert_eq!(lunar_month_days(2024, 2, true), None);
+        assert_eq!(LunarDate::new(2024, 1, 30, false), None);
+    }
+
+    #[test]
+    fn test_festivals() {
+        assert_eq!(lantern_festival(2024), Some(date!(2024 - 02 - 24)));
+        assert_eq!(buddhas_birthday(2024), Some(date!(2024 - 05 - 15)));
+        assert_eq!(dragon_boat_festival(2024), Some(date!(2024 - 06 - 10)));
+        assert_eq!(mid_autumn_festival(2024), Some(date!(2024 - 09 - 17)));
+        assert_eq!(double_ninth_festival(2024), Some(date!(2024 - 10 - 11)));
+        assert_eq!(mid_autumn_festival(2023), Some(date!(2023 - 09 - 29)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let first = date!(1900 - 01 - 31);
+        let last_day = lunar_month_days(2100, 12, false).unwrap();
+        let last = LunarDate::new(2100, 12, last_day, false)
+            .unwrap()
+            .to_gregorian();
+
+        for date in date_sequence(first, last) {
+            let lunar = LunarDate::from_gregorian(date).unwrap();
+            assert_eq!(lunar.to_gregorian(), date);
+        }
+
+        assert_eq!(
+            LunarDate::from_gregorian(last).map(|l| l.day),
+            Some(last_day)
+        );
+        assert_eq!(
+            LunarDate::from_gregorian(first.previous_day().unwrap()),
+            None
+        );
+        assert_eq!(LunarDate::from_gregorian(last.next_day().unwrap()), None);
+    }
+}
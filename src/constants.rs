@@ -34,16 +34,20 @@ pub(crate) const SECS_IN_WEEK: usize = MINS_IN_WEEK * SECS_IN_HOUR;
 pub(crate) const SECS_IN_YEAR: usize = SECS_IN_MIN * MINS_IN_HOUR * HOURS_IN_DAY * DAYS_IN_YEAR;
 
 // Frequency constants
+pub(crate) const ONCE: isize = 0;
 pub(crate) const ANNUALLY: isize = 1;
 pub(crate) const SEMI_ANNUALLY: isize = 2;
 pub(crate) const TRI_ANNUALLY: isize = 3;
 pub(crate) const QUARTERLY: isize = 4;
-pub(crate) const SEMI_QUARTERLY: isize = 6;
+pub(crate) const BI_MONTHLY: isize = 6;
+pub(crate) const SEMI_QUARTERLY: isize = 8;
 pub(crate) const MONTHLY: isize = 12;
+pub(crate) const EVERY_FOUR_WEEKS: isize = 13;
 pub(crate) const SEMI_MONTHLY: isize = 24;
 pub(crate) const BI_WEEKLY: isize = 26;
 pub(crate) const WEEKLY: isize = 52;
-pub(crate) const DAILY: isize = 252;
+pub(crate) const BUSINESS_DAILY: isize = 252;
+pub(crate) const DAILY: isize = 365;
 
 /// Easter Mondays for the years 1901-2199.
 /// The first row is for Western Easter, the second for Orthodox Easter.
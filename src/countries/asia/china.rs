@@ -13,9 +13,10 @@
 // IMPORTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::calendar::Calendar;
+use crate::calendar::{Calendar, EarlyClose};
 use crate::utilities::unpack_date;
-use time::{Date, Month};
+use time::macros::date;
+use time::{Date, Month, Time};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, TRAITS
@@ -24,6 +25,64 @@ use time::{Date, Month};
 /// China national holiday calendar.
 pub struct ChinaCalendar;
 
+/// China interbank (IB) calendar, of the China Foreign Exchange Trade System (CFETS).
+///
+/// The national holidays. Interbank trades settle on the weekend days
+/// adjusted to working days (调休) around them.
+pub struct ChinaInterbankCalendar;
+
+/// Shanghai Stock Exchange (SSE) calendar.
+///
+/// The national holidays. The exchange is closed on all weekends,
+/// including the adjusted working weekend days.
+#[derive(Default)]
+pub struct SseCalendar;
+
+/// Shenzhen Stock Exchange (SZSE) calendar.
+///
+/// The national holidays. The exchange is closed on all weekends,
+/// including the adjusted working weekend days.
+#[derive(Default)]
+pub struct SzseCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Weekend days adjusted to working days (调休), by year.
+///
+/// Each of them makes up for a holiday of `ChinaCalendar` within ten days,
+/// so the holidays must be listed for every year of this table.
+#[rustfmt::skip]
+const WORKING_WEEKENDS: [&[Date]; 23] = [
+    &[date!(2004 - 01 - 17), date!(2004 - 01 - 18), date!(2004 - 05 - 08), date!(2004 - 05 - 09), date!(2004 - 10 - 09), date!(2004 - 10 - 10)],
+    &[date!(2005 - 02 - 05), date!(2005 - 02 - 06), date!(2005 - 04 - 30), date!(2005 - 05 - 08), date!(2005 - 10 - 08), date!(2005 - 10 - 09), date!(2005 - 12 - 31)],
+    &[date!(2006 - 01 - 28), date!(2006 - 02 - 05), date!(2006 - 04 - 29), date!(2006 - 04 - 30), date!(2006 - 09 - 30), date!(2006 - 10 - 08), date!(2006 - 12 - 30), date!(2006 - 12 - 31)],
+    &[date!(2007 - 02 - 17), date!(2007 - 02 - 25), date!(2007 - 04 - 28), date!(2007 - 04 - 29), date!(2007 - 09 - 29), date!(2007 - 09 - 30), date!(2007 - 12 - 29)],
+    &[date!(2008 - 02 - 02), date!(2008 - 02 - 03), date!(2008 - 05 - 04), date!(2008 - 09 - 27), date!(2008 - 09 - 28)],
+    &[date!(2009 - 01 - 04), date!(2009 - 01 - 24), date!(2009 - 02 - 01), date!(2009 - 05 - 31), date!(2009 - 09 - 27), date!(2009 - 10 - 10)],
+    &[date!(2010 - 02 - 20), date!(2010 - 02 - 21), date!(2010 - 06 - 12), date!(2010 - 06 - 13), date!(2010 - 09 - 19), date!(2010 - 09 - 25), date!(2010 - 09 - 26), date!(2010 - 10 - 09)],
+    &[date!(2011 - 01 - 30), date!(2011 - 02 - 12), date!(2011 - 04 - 02), date!(2011 - 10 - 08), date!(2011 - 10 - 09), date!(2011 - 12 - 31)],
+    &[date!(2012 - 01 - 21), date!(2012 - 01 - 29), date!(2012 - 03 - 31), date!(2012 - 04 - 01), date!(2012 - 04 - 28), date!(2012 - 09 - 29)],
+    &[date!(2013 - 01 - 05), date!(2013 - 01 - 06), date!(2013 - 02 - 16), date!(2013 - 02 - 17), date!(2013 - 04 - 07), date!(2013 - 04 - 27), date!(2013 - 04 - 28), date!(2013 - 06 - 08), date!(2013 - 06 - 09), date!(2013 - 09 - 22), date!(2013 - 09 - 29), date!(2013 - 10 - 12)],
+    &[date!(2014 - 01 - 26), date!(2014 - 02 - 08), date!(2014 - 05 - 04), date!(2014 - 09 - 28), date!(2014 - 10 - 11)],
+    &[date!(2015 - 01 - 04), date!(2015 - 02 - 15), date!(2015 - 02 - 28), date!(2015 - 09 - 06), date!(2015 - 10 - 10)],
+    &[date!(2016 - 02 - 06), date!(2016 - 02 - 14), date!(2016 - 06 - 12), date!(2016 - 09 - 18), date!(2016 - 10 - 08), date!(2016 - 10 - 09)],
+    &[date!(2017 - 01 - 22), date!(2017 - 02 - 04), date!(2017 - 04 - 01), date!(2017 - 05 - 27), date!(2017 - 09 - 30)],
+    &[date!(2018 - 02 - 11), date!(2018 - 02 - 24), date!(2018 - 04 - 08), date!(2018 - 04 - 28), date!(2018 - 09 - 29), date!(2018 - 09 - 30), date!(2018 - 12 - 29)],
+    &[date!(2019 - 02 - 02), date!(2019 - 02 - 03), date!(2019 - 04 - 28), date!(2019 - 05 - 05), date!(2019 - 09 - 29), date!(2019 - 10 - 12)],
+    &[date!(2020 - 01 - 19), date!(2020 - 02 - 01), date!(2020 - 04 - 26), date!(2020 - 05 - 09), date!(2020 - 06 - 28), date!(2020 - 09 - 27), date!(2020 - 10 - 10)],
+    &[date!(2021 - 02 - 07), date!(2021 - 02 - 20), date!(2021 - 04 - 25), date!(2021 - 05 - 08), date!(2021 - 09 - 18), date!(2021 - 09 - 26), date!(2021 - 10 - 09)],
+    &[date!(2022 - 01 - 29), date!(2022 - 01 - 30), date!(2022 - 04 - 02), date!(2022 - 04 - 24), date!(2022 - 05 - 07), date!(2022 - 10 - 08), date!(2022 - 10 - 09)],
+    &[date!(2023 - 01 - 28), date!(2023 - 01 - 29), date!(2023 - 04 - 23), date!(2023 - 05 - 06), date!(2023 - 06 - 25), date!(2023 - 10 - 07), date!(2023 - 10 - 08)],
+    &[date!(2024 - 02 - 04), date!(2024 - 02 - 18), date!(2024 - 04 - 07), date!(2024 - 04 - 28), date!(2024 - 05 - 11), date!(2024 - 09 - 14), date!(2024 - 09 - 29), date!(2024 - 10 - 12)],
+    &[date!(2025 - 01 - 26), date!(2025 - 02 - 08), date!(2025 - 04 - 27), date!(2025 - 09 - 28), date!(2025 - 10 - 11)],
+    &[date!(2026 - 01 - 04), date!(2026 - 02 - 14), date!(2026 - 02 - 28), date!(2026 - 05 - 09), date!(2026 - 09 - 20), date!(2026 - 10 - 10)],
+];
+
+/// First year of the adjusted working weekend days.
+const WORKING_WEEKENDS_FIRST_YEAR: i32 = 2004;
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS, METHODS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -57,6 +116,7 @@ impl Calendar for ChinaCalendar {
             || (y == 2021 && d == 1 && m == Month::January)
             || (y == 2022 && d == 3 && m == Month::January)
             || (y == 2023 && d == 2 && m == Month::January)
+            || (y == 2026 && d <= 2 && m == Month::January)
 
             // Chinese New Year
             || (y == 2004 && d >= 19 && d <= 28 && m == Month::January)
@@ -81,6 +141,9 @@ impl Calendar for ChinaCalendar {
             || (y == 2021 && (d == 11 || d == 12 || d == 15 || d == 16 || d == 17) && m == Month::February)
             || (y == 2022 && ((d == 31 && m == Month::January) || (d <= 4 && m == Month::February)))
             || (y == 2023 && d >= 23 && d <= 27 && m == Month::January)
+            || (y == 2024 && (12..=16).contains(&d) && m == Month::February)
+            || (y == 2025 && ((d >= 28 && m == Month::January) || (d <= 4 && m == Month::February)))
+            || (y == 2026 && (16..=23).contains(&d) && m == Month::February)
 
             // Ching Ming Festival
             || (y <= 2008 && d == 4 && m == Month::April)
@@ -99,6 +162,9 @@ impl Calendar for ChinaCalendar {
             || (y == 2021 && d == 5 && m == Month::April)
             || (y == 2022 && d >= 4 && d <= 5 && m == Month::April)
             || (y == 2023 && d == 5 && m == Month::April)
+            || (y == 2024 && (4..=5).contains(&d) && m == Month::April)
+            || (y == 2025 && d == 4 && m == Month::April)
+            || (y == 2026 && d == 6 && m == Month::April)
 
             // Labor Day
             || (y <= 2007 && d >= 1 && d <= 7 && m == Month::May)
@@ -118,6 +184,9 @@ impl Calendar for ChinaCalendar {
             || (y == 2021 && (d == 3 || d == 4 || d == 5) && m == Month::May)
             || (y == 2022 && d >= 2 && d <= 4 && m == Month::May)
             || (y == 2023 && d >= 1 && d <= 3 && m == Month::May)
+            || (y == 2024 && (1..=3).contains(&d) && m == Month::May)
+            || (y == 2025 && (d == 1 || d == 2 || d == 5) && m == Month::May)
+            || (y == 2026 && (d == 1 || d == 4 || d == 5) && m == Month::May)
 
             // Tuen Ng Festival
             || (y <= 2008 && d == 9 && m == Month::June)
@@ -136,6 +205,9 @@ impl Calendar for ChinaCalendar {
             || (y == 2021 && d == 14 && m == Month::June)
             || (y == 2022 && d == 3 && m == Month::June)
             || (y == 2023 && d >= 22 && d <= 23 && m == Month::June)
+            || (y == 2024 && d == 10 && m == Month::June)
+            || (y == 2025 && d == 2 && m == Month::June)
+            || (y == 2026 && d == 19 && m == Month::June)
 
             // Mid-Autumn Festival
             || (y <= 2008 && d == 15 && m == Month::September)
@@ -151,6 +223,8 @@ impl Calendar for ChinaCalendar {
             || (y == 2021 && (d == 20 || d == 21) && m == Month::September)
             || (y == 2022 && d == 12 && m == Month::September)
             || (y == 2023 && d == 29 && m == Month::September)
+            || (y == 2024 && (16..=17).contains(&d) && m == Month::September)
+            || (y == 2026 && d == 25 && m == Month::September)
 
             // National Day
             || (y <= 2007 && d >= 1 && d <= 7 && m == Month::October)
@@ -171,6 +245,9 @@ impl Calendar for ChinaCalendar {
             || (y == 2021 && (d == 1 || d == 4 || d == 5 || d == 6 || d == 7) && m == Month::October)
             || (y == 2022 && d >= 3 && d <= 7 && m == Month::October)
             || (y == 2023 && d >= 2 && d <= 6 && m == Month::October)
+            || (y == 2024 && (1..=7).contains(&d) && m == Month::October)
+            || (y == 2025 && (1..=8).contains(&d) && m == Month::October)
+            || (y == 2026 && (1..=7).contains(&d) && m == Month::October)
             
             // 70th anniversary of the victory of anti-Japanese war
             || (y == 2015 && (3..=4).contains(&d) && m == Month::September)
@@ -182,6 +259,155 @@ impl Calendar for ChinaCalendar {
     }
 }
 
+impl Calendar for ChinaInterbankCalendar {
+    fn name(&self) -> &'static str {
+        "China Interbank"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        ChinaCalendar.is_holiday(date)
+    }
+
+    fn is_working_weekend(&self, date: Date) -> bool {
+        let index = date.year() - WORKING_WEEKENDS_FIRST_YEAR;
+
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| WORKING_WEEKENDS.get(index))
+            .is_some_and(|days| days.contains(&date))
+    }
+}
+
+impl Calendar for SseCalendar {
+    fn name(&self) -> &'static str {
+        "Shanghai Stock Exchange"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        ChinaCalendar.is_holiday(date)
+    }
+}
+
+impl Calendar for SzseCalendar {
+    fn name(&self) -> &'static str {
+        "Shenzhen Stock Exchange"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        ChinaCalendar.is_holiday(date)
+    }
+}
+
+impl EarlyClose for SseCalendar {
+    /// The SSE has no shortened trading sessions.
+    fn early_close(&self, _date: Date) -> Option<Time> {
+        None
+    }
+}
+
+impl EarlyClose for SzseCalendar {
+    /// The SZSE has no shortened trading sessions.
+    fn early_close(&self, _date: Date) -> Option<Time> {
+        None
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // UNIT TESTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_china {
+    use super::*;
+    use time::Duration;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        assert_eq!(ChinaCalendar.name(), "China");
+        assert_eq!(ChinaInterbankCalendar.name(), "China Interbank");
+        assert_eq!(SseCalendar.name(), "Shanghai Stock Exchange");
+        assert_eq!(SzseCalendar.name(), "Shenzhen Stock Exchange");
+    }
+
+    // Test to verify the Spring Festival and National Day holidays.
+    #[test]
+    fn test_is_public_holiday() {
+        let calendar = ChinaCalendar;
+
+        assert!(!calendar.is_business_day(date!(2023 - 01 - 23)));
+        assert!(!calendar.is_business_day(date!(2023 - 01 - 27)));
+        assert!(!calendar.is_business_day(date!(2023 - 10 - 06)));
+        assert!(calendar.is_business_day(date!(2023 - 01 - 30)));
+
+        assert!(!calendar.is_business_day(date!(2024 - 02 - 12)));
+        assert!(!calendar.is_business_day(date!(2024 - 02 - 16)));
+        assert!(!calendar.is_business_day(date!(2025 - 02 - 04)));
+        assert!(!calendar.is_business_day(date!(2025 - 10 - 08)));
+        assert!(!calendar.is_business_day(date!(2026 - 01 - 02)));
+        assert!(!calendar.is_business_day(date!(2026 - 02 - 23)));
+        assert!(calendar.is_business_day(date!(2024 - 02 - 19)));
+    }
+
+    // Test to verify the adjusted working weekend days, which are business days
+    // for the interbank market, but not for the national calendar or the exchanges.
+    #[test]
+    fn test_is_working_weekend() {
+        let working_weekend = date!(2024 - 02 - 18);
+        let weekend = date!(2024 - 02 - 25);
+
+        assert!(ChinaInterbankCalendar.is_working_weekend(working_weekend));
+        assert!(ChinaInterbankCalendar.is_business_day(working_weekend));
+        assert!(!ChinaCalendar.is_working_weekend(working_weekend));
+        assert!(!ChinaCalendar.is_business_day(working_weekend));
+        assert!(!SseCalendar.is_business_day(working_weekend));
+        assert!(!SzseCalendar.is_business_day(working_weekend));
+
+        assert!(!ChinaInterbankCalendar.is_business_day(weekend));
+        assert!(ChinaInterbankCalendar.is_business_day(date!(2004 - 01 - 17)));
+        assert!(!ChinaInterbankCalendar.is_working_weekend(date!(2003 - 01 - 04)));
+
+        assert_eq!(
+            ChinaInterbankCalendar
+                .all_business_days_between(date!(2023 - 09 - 28), date!(2023 - 10 - 10)),
+            vec![
+                date!(2023 - 09 - 28),
+                date!(2023 - 10 - 07),
+                date!(2023 - 10 - 08),
+                date!(2023 - 10 - 09),
+                date!(2023 - 10 - 10),
+            ]
+        );
+    }
+
+    // Test to verify every working weekend day makes up for a national holiday
+    // within ten days, so that the holidays cover the years of the working weekends.
+    #[test]
+    fn test_working_weekends_next_to_holidays() {
+        for (index, days) in WORKING_WEEKENDS.iter().enumerate() {
+            for &day in days.iter() {
+                assert_eq!(day.year(), WORKING_WEEKENDS_FIRST_YEAR + index as i32);
+                assert!(
+                    (-10..=10).any(|offset| ChinaCalendar.is_holiday(day + Duration::days(offset))),
+                    "no holiday around the working weekend day {day}"
+                );
+            }
+        }
+
+        // Spring Festival 2026, with the Saturdays before and after it worked
+        assert_eq!(
+            ChinaInterbankCalendar
+                .all_business_days_between(date!(2026 - 02 - 13), date!(2026 - 03 - 02)),
+            vec![
+                date!(2026 - 02 - 13),
+                date!(2026 - 02 - 14),
+                date!(2026 - 02 - 24),
+                date!(2026 - 02 - 25),
+                date!(2026 - 02 - 26),
+                date!(2026 - 02 - 27),
+                date!(2026 - 02 - 28),
+                date!(2026 - 03 - 02),
+            ]
+        );
+    }
+}
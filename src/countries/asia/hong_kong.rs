@@ -13,9 +13,15 @@
 // IMPORTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::calendar::Calendar;
+use crate::astronomy::{solar_term_date, SolarTerm};
+use crate::calendar::{Calendar, EarlyClose};
+use crate::chinese_calendar::{
+    buddhas_birthday, chinese_new_year, double_ninth_festival, dragon_boat_festival,
+    mid_autumn_festival,
+};
 use crate::utilities::unpack_date;
-use time::{Date, Month, Weekday};
+use time::macros::{date, offset, time};
+use time::{Date, Duration, Month, Time, Weekday};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, TRAITS
@@ -24,6 +30,96 @@ use time::{Date, Month, Weekday};
 /// HongKong national holiday calendar.
 pub struct HongKongCalendar;
 
+/// Hong Kong Exchanges and Clearing (HKEX) calendar.
+///
+/// The Hong Kong holiday calendar, plus the trading days lost to typhoon
+/// signal No. 8 (or above) and black rainstorm warnings.
+/// The historical record is loaded by `HkexCalendar::new()`,
+/// and new closures can be added at runtime.
+#[derive(Debug, Clone)]
+pub struct HkexCalendar {
+    weather_closures: Vec<WeatherClosure>,
+}
+
+/// Weather warnings that suspend trading on HKEX.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherWarning {
+    /// Tropical cyclone warning signal No. 8 or above.
+    TyphoonSignal8,
+
+    /// Black rainstorm warning signal.
+    BlackRainstorm,
+}
+
+/// Trading sessions cancelled by a weather warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherClosureKind {
+    /// No trading for the whole day
+    /// (warning in force after 12:00 noon).
+    FullDay,
+
+    /// Morning session cancelled, afternoon session held
+    /// (warning cancelled at or before 12:00 noon).
+    MorningSession,
+}
+
+/// A trading day affected by a weather warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeatherClosure {
+    /// Date of the closure.
+    pub date: Date,
+
+    /// Weather warning in force.
+    pub warning: WeatherWarning,
+
+    /// Trading sessions cancelled.
+    pub kind: WeatherClosureKind,
+}
+
+/// Known historical HKEX weather closures since 2000: the trading days on which a
+/// typhoon signal No. 8 (or above) or a black rainstorm warning was in force at the
+/// opening, or was lowered too late for the morning session.
+///
+/// Each entry names the weather warning in force, and the sessions cancelled
+/// under the HKEX trading arrangements for severe weather: no trading for the day
+/// if the warning was still in force after 12:00 noon, and the morning session
+/// only if it was lowered at or before 12:00 noon.
+///
+/// Sources: the Hong Kong Observatory (HKO) records of the tropical cyclone warning
+/// signals issued for each cyclone (by name and international number, as in the
+/// HKO "Tropical Cyclones in <year>" reports and its Warnings and Signals Database),
+/// and of the black rainstorm warning of 7 - 8 September 2023.
+///
+/// HKEX introduced severe weather trading on 23 September 2024,
+/// so no closures are expected after that date.
+#[rustfmt::skip]
+pub const HKEX_WEATHER_CLOSURES: [WeatherClosure; 24] = [
+    WeatherClosure::new(date!(2001 - 07 - 06), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Utor (0104), No. 8: no trading
+    WeatherClosure::new(date!(2001 - 07 - 25), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Yutu (0107), No. 8: no trading
+    WeatherClosure::new(date!(2003 - 07 - 24), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Imbudo (0307), No. 8: morning session cancelled
+    WeatherClosure::new(date!(2008 - 08 - 06), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Kammuri (0809), No. 8: no trading
+    WeatherClosure::new(date!(2008 - 08 - 22), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Nuri (0812), No. 8: no trading
+    WeatherClosure::new(date!(2008 - 09 - 24), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Hagupit (0814), No. 8: morning session cancelled
+    WeatherClosure::new(date!(2011 - 09 - 29), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Nesat (1117), No. 8: no trading
+    WeatherClosure::new(date!(2012 - 07 - 24), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Vicente (1208), No. 10: morning session cancelled
+    WeatherClosure::new(date!(2012 - 08 - 17), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Kai-tak (1213), No. 8: morning session cancelled
+    WeatherClosure::new(date!(2013 - 08 - 14), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Utor (1311), No. 8: no trading
+    WeatherClosure::new(date!(2013 - 09 - 23), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Usagi (1319), No. 8: morning session cancelled
+    WeatherClosure::new(date!(2014 - 09 - 16), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Kalmaegi (1415), No. 8: morning session cancelled
+    WeatherClosure::new(date!(2016 - 08 - 02), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Nida (1604), No. 8: no trading
+    WeatherClosure::new(date!(2016 - 10 - 21), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Haima (1622), No. 8: no trading
+    WeatherClosure::new(date!(2017 - 08 - 23), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Hato (1713), No. 10: no trading
+    WeatherClosure::new(date!(2020 - 08 - 19), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Higos (2007), No. 8: morning session cancelled
+    WeatherClosure::new(date!(2020 - 10 - 13), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Nangka (2016), No. 8: morning session cancelled
+    WeatherClosure::new(date!(2021 - 10 - 13), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Kompasu (2118), No. 8: no trading
+    WeatherClosure::new(date!(2022 - 08 - 25), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Ma-on (2209), No. 8: morning session cancelled
+    WeatherClosure::new(date!(2023 - 07 - 17), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Talim (2304), No. 8: no trading
+    WeatherClosure::new(date!(2023 - 09 - 01), WeatherWarning::TyphoonSignal8, WeatherClosureKind::FullDay),            // Saola (2309), No. 10: no trading
+    WeatherClosure::new(date!(2023 - 09 - 08), WeatherWarning::BlackRainstorm, WeatherClosureKind::FullDay),            // black rainstorm warning (7 - 8 September): no trading
+    WeatherClosure::new(date!(2023 - 10 - 09), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Koinu (2314), No. 8: morning session cancelled
+    WeatherClosure::new(date!(2024 - 09 - 06), WeatherWarning::TyphoonSignal8, WeatherClosureKind::MorningSession),     // Yagi (2411), No. 8: morning session cancelled
+];
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS, METHODS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -54,17 +150,17 @@ impl Calendar for HongKongCalendar {
                 // Boxing Day
                 || (d == 26 && m == Month::December)
                 // Lunar New Year
-                || self.is_lunar_new_year(y, m, d)
+                || self.is_lunar_new_year(date)
                 // Buddha's birthday
-                || self.is_buddhas_birthday(y, m,d)
+                || self.is_buddhas_birthday(date)
                 // Ching Ming Festival
-                || self.is_ching_ming_festival(y, m,d)
+                || self.is_ching_ming_festival(date)
                 // Tuen Ng festival
-                || self.is_tuen_ng_festival(y, m,d)
+                || self.is_tuen_ng_festival(date)
                 // Mid-autumn festival
-                || self.is_mid_autumn_festival(y, m,d)
+                || self.is_mid_autumn_festival(date)
                 // Chung Yeung festival
-                || self.is_chung_yeung_festival(y, m,d)
+                || self.is_chung_yeung_festival(date)
                 // Second day after Christmas
                 || self.is_second_day_after_christmas(y, m,d)
         ) {
@@ -75,157 +171,157 @@ impl Calendar for HongKongCalendar {
     }
 }
 
+impl WeatherClosure {
+    /// Create a new weather closure.
+    pub const fn new(date: Date, warning: WeatherWarning, kind: WeatherClosureKind) -> Self {
+        Self {
+            date,
+            warning,
+            kind,
+        }
+    }
+}
+
+impl Calendar for HkexCalendar {
+    fn name(&self) -> &'static str {
+        "Hong Kong Exchanges and Clearing"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        HongKongCalendar.is_holiday(date)
+            || self
+                .weather_closure(date)
+                .is_some_and(|closure| closure.kind == WeatherClosureKind::FullDay)
+    }
+}
+
+impl EarlyClose for HkexCalendar {
+    /// Half-day trading (morning session only) on the eves of
+    /// Christmas, New Year and Lunar New Year.
+    fn early_close(&self, date: Date) -> Option<Time> {
+        let (_, m, d, _, _, _) = unpack_date(date, false);
+        let next = date.next_day().unwrap();
+
+        if self.is_business_day(date)
+            && (((d == 24 || d == 31) && m == Month::December)
+                || chinese_new_year(next.year()) == Some(next))
+        {
+            return Some(time!(12:00));
+        }
+
+        None
+    }
+
+    /// Afternoon session only, when the morning session is cancelled.
+    fn late_open(&self, date: Date) -> Option<Time> {
+        match self.weather_closure(date)?.kind {
+            WeatherClosureKind::MorningSession => Some(time!(13:00)),
+            WeatherClosureKind::FullDay => None,
+        }
+    }
+}
+
+impl Default for HkexCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HkexCalendar {
+    /// Create a new instance of the HKEX calendar, with the historical weather closures.
+    pub fn new() -> Self {
+        Self {
+            weather_closures: HKEX_WEATHER_CLOSURES.to_vec(),
+        }
+    }
+
+    /// Record a weather closure (replacing any closure already recorded for the date).
+    pub fn add_weather_closure(&mut self, closure: WeatherClosure) {
+        self.weather_closures.retain(|c| c.date != closure.date);
+        self.weather_closures.push(closure);
+        self.weather_closures.sort_by_key(|c| c.date);
+    }
+
+    /// All recorded weather closures.
+    pub fn weather_closures(&self) -> &[WeatherClosure] {
+        &self.weather_closures
+    }
+
+    /// Weather closure recorded for the date, if any.
+    pub fn weather_closure(&self, date: Date) -> Option<&WeatherClosure> {
+        self.weather_closures.iter().find(|c| c.date == date)
+    }
+}
+
 impl HongKongCalendar {
     /// Create a new instance of the Hong Kong calendar.
     pub fn new() -> Self {
         Self
     }
 
-    #[allow(overlapping_range_endpoints)]
-    fn is_lunar_new_year(&self, year: i32, month: Month, day: u8) -> bool {
-        use Month::{February, January};
+    fn is_lunar_new_year(&self, date: Date) -> bool {
+        let Some(new_year) = chinese_new_year(date.year()) else {
+            return false;
+        };
 
-        matches!(
-            (year, day, month),
-            (2004, 22..=24, January)
-                | (2005, 9..=11, February)
-                | (2006, 28..=30, January)
-                | (2007, 17..=19, February)
-                | (2008, 7..=9, February)
-                | (2009, 26..=28, January)
-                | (2010, 13..=15, February)
-                | (2011, 2..=4, February)
-                | (2012, 23..=25, January)
-                | (2013, 10..=12, February)
-                | (2014, 31 | 1 | 2, January)
-                | (2015, 19..=21, February)
-                | (2016, 8..=10, February)
-                | (2017, 28..=30, January)
-                | (2018, 16..=18, February)
-                | (2019, 5..=7, February)
-                | (2020, 25 | 27 | 28, January)
-        )
+        // the first three days of the first lunar month
+        if (0..3).contains(&(date - new_year).whole_days()) {
+            return true;
+        }
+
+        // if one of them is a Sunday, the fourth day
+        // (or Lunar New Year's Eve before 2013, if the first day is a Sunday)
+        let on_sunday = (0..3).any(|i| (new_year + Duration::days(i)).weekday() == Weekday::Sunday);
+
+        match (new_year.weekday(), date.year()) {
+            _ if !on_sunday => false,
+            (Weekday::Sunday, ..=2012) => date == new_year - Duration::days(1),
+            _ => date == new_year + Duration::days(3),
+        }
     }
 
-    fn is_buddhas_birthday(&self, year: i32, month: Month, day: u8) -> bool {
-        use Month::May;
-        matches!(
-            (year, day, month),
-            (2004, 26, May)
-                | (2005, 16, May)
-                | (2006, 5, May)
-                | (2007, 24, May)
-                | (2008, 12, May)
-                | (2009, 2, May)
-                | (2010, 21, May)
-                | (2011, 10, May)
-                | (2012, 28, May)
-                | (2013, 17, May)
-                | (2014, 6, May)
-                | (2015, 25, May)
-                | (2016, 14, May)
-                | (2017, 3, May)
-                | (2018, 22, May)
-                | (2019, 12, May)
-                | (2020, 30, May)
-        )
+    fn is_buddhas_birthday(&self, date: Date) -> bool {
+        // public holiday since 1999 (Monday if on a Sunday)
+        date.year() >= 1999 && self.is_lunar_holiday(date, buddhas_birthday(date.year()))
     }
 
-    fn is_ching_ming_festival(&self, year: i32, month: Month, day: u8) -> bool {
-        use Month::April;
-        matches!(
-            (year, day, month),
-            (2004, 5, April)
-                | (2005, 5, April)
-                | (2006, 5, April)
-                | (2007, 5, April)
-                | (2008, 4, April)
-                | (2009, 4, April)
-                | (2010, 5, April)
-                | (2011, 5, April)
-                | (2012, 4, April)
-                | (2013, 4, April)
-                | (2014, 5, April)
-                | (2015, 5, April)
-                | (2016, 4, April)
-                | (2017, 4, April)
-                | (2018, 5, April)
-                | (2019, 5, April)
-                | (2020, 4, April)
-        )
+    fn is_ching_ming_festival(&self, date: Date) -> bool {
+        // Qingming solar term, in Hong Kong time
+        let qingming = solar_term_date(date.year(), SolarTerm::PureBrightness, offset!(+8));
+        self.is_lunar_holiday(date, Some(qingming))
     }
 
-    fn is_tuen_ng_festival(&self, year: i32, month: Month, day: u8) -> bool {
-        use Month::{June, May};
+    fn is_tuen_ng_festival(&self, date: Date) -> bool {
+        self.is_lunar_holiday(date, dragon_boat_festival(date.year()))
+    }
 
-        matches!(
-            (year, day, month),
-            (2004, 22, June)
-                | (2005, 11, June)
-                | (2006, 1, June)
-                | (2007, 19, June)
-                | (2008, 9, June)
-                | (2009, 28, June)
-                | (2010, 16, June)
-                | (2011, 6, June)
-                | (2012, 23, June)
-                | (2013, 12, June)
-                | (2014, 2, June)
-                | (2015, 20, June)
-                | (2016, 9, June)
-                | (2017, 30, May)
-                | (2018, 18, June)
-                | (2019, 7, June)
-                | (2020, 25, June)
-        )
+    fn is_mid_autumn_festival(&self, date: Date) -> bool {
+        let Some(mid_autumn) = mid_autumn_festival(date.year()) else {
+            return false;
+        };
+
+        // the day following the festival, or the second day following it if that day
+        // is a Sunday, then the next day if it is National Day
+        let mut observed = mid_autumn + Duration::days(1);
+        while observed.weekday() == Weekday::Sunday
+            || (observed.month() == Month::October && observed.day() == 1)
+        {
+            observed += Duration::days(1);
+        }
+
+        date == observed
     }
 
-    fn is_mid_autumn_festival(&self, year: i32, month: Month, day: u8) -> bool {
-        use Month::{October, September};
-        matches!(
-            (year, day, month),
-            (2004, 28, September)
-                | (2005, 18, September)
-                | (2006, 7, October)
-                | (2007, 26, September)
-                | (2008, 15, September)
-                | (2009, 3, October)
-                | (2010, 22, September)
-                | (2011, 12, September)
-                | (2012, 30, September)
-                | (2013, 19, September)
-                | (2014, 9, September)
-                | (2015, 27, September)
-                | (2016, 15, September)
-                | (2017, 5, October)
-                | (2018, 25, September)
-                | (2019, 14, September)
-                | (2020, 1, October)
-        )
+    fn is_chung_yeung_festival(&self, date: Date) -> bool {
+        self.is_lunar_holiday(date, double_ninth_festival(date.year()))
     }
 
-    fn is_chung_yeung_festival(&self, year: i32, month: Month, day: u8) -> bool {
-        use Month::October;
-        matches!(
-            (year, day, month),
-            (2004, 28, October)
-                | (2005, 18, October)
-                | (2006, 7, October)
-                | (2007, 26, October)
-                | (2008, 16, October)
-                | (2009, 26, October)
-                | (2010, 16, October)
-                | (2011, 5, October)
-                | (2012, 23, October)
-                | (2013, 14, October)
-                | (2014, 2, October)
-                | (2015, 21, October)
-                | (2016, 10, October)
-                | (2017, 28, October)
-                | (2018, 17, October)
-                | (2019, 7, October)
-                | (2020, 26, October)
-        )
+    /// Function to check if the date is a festival, or the following Monday if on a Sunday.
+    fn is_lunar_holiday(&self, date: Date, festival: Option<Date>) -> bool {
+        festival.is_some_and(|festival| {
+            date == festival
+                || (festival.weekday() == Weekday::Sunday && date == festival + Duration::days(1))
+        })
     }
 
     fn is_second_day_after_christmas(&self, year: i32, month: Month, day: u8) -> bool {
@@ -256,3 +352,141 @@ impl HongKongCalendar {
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // UNIT TESTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_hong_kong {
+    use super::*;
+
+    // Test to verify the historical HKEX weather closures.
+    #[test]
+    fn test_hkex_weather_closures() {
+        let calendar = HkexCalendar::new();
+        assert_eq!(calendar.name(), "Hong Kong Exchanges and Clearing");
+
+        // Black rainstorm, 8 September 2023
+        assert!(!calendar.is_business_day(date!(2023 - 09 - 08)));
+        assert!(HongKongCalendar.is_business_day(date!(2023 - 09 - 08)));
+
+        // Morning session cancelled for Koinu, 9 October 2023
+        assert!(calendar.is_business_day(date!(2023 - 10 - 09)));
+        assert_eq!(
+            calendar.late_open(date!(2023 - 10 - 09)),
+            Some(time!(13:00))
+        );
+        assert_eq!(calendar.late_open(date!(2023 - 10 - 10)), None);
+
+        // Full day closure for Utor, 6 July 2001
+        assert!(!calendar.is_business_day(date!(2001 - 07 - 06)));
+        assert!(HongKongCalendar.is_business_day(date!(2001 - 07 - 06)));
+
+        // Morning session cancelled for Imbudo, 24 July 2003
+        assert!(calendar.is_business_day(date!(2003 - 07 - 24)));
+        assert_eq!(
+            calendar.late_open(date!(2003 - 07 - 24)),
+            Some(time!(13:00))
+        );
+
+        // Full day closure for Nida, 2 August 2016
+        assert!(!calendar.is_business_day(date!(2016 - 08 - 02)));
+
+        // All the recorded closures are on Hong Kong business days
+        for closure in HKEX_WEATHER_CLOSURES {
+            assert!(HongKongCalendar.is_business_day(closure.date));
+        }
+        assert!(HKEX_WEATHER_CLOSURES
+            .windows(2)
+            .all(|pair| pair[0].date < pair[1].date));
+    }
+
+    // Test to verify that weather closures can be added at runtime.
+    #[test]
+    fn test_hkex_add_weather_closure() {
+        let mut calendar = HkexCalendar::new();
+        let date = date!(2024 - 07 - 15);
+
+        assert!(calendar.is_business_day(date));
+
+        calendar.add_weather_closure(WeatherClosure::new(
+            date,
+            WeatherWarning::BlackRainstorm,
+            WeatherClosureKind::MorningSession,
+        ));
+        assert!(calendar.is_business_day(date));
+        assert_eq!(calendar.late_open(date), Some(time!(13:00)));
+
+        calendar.add_weather_closure(WeatherClosure::new(
+            date,
+            WeatherWarning::BlackRainstorm,
+            WeatherClosureKind::FullDay,
+        ));
+        assert!(!calendar.is_business_day(date));
+        assert_eq!(
+            calendar.weather_closures().len(),
+            HKEX_WEATHER_CLOSURES.len() + 1
+        );
+    }
+
+    // Test to verify the HKEX half-day trading sessions.
+    #[test]
+    fn test_hkex_half_days() {
+        let calendar = HkexCalendar::new();
+
+        assert_eq!(
+            calendar.early_close(date!(2024 - 12 - 24)),
+            Some(time!(12:00))
+        );
+        assert_eq!(
+            calendar.early_close(date!(2024 - 12 - 31)),
+            Some(time!(12:00))
+        );
+
+        // Lunar New Year's Eve, 2019
+        assert_eq!(
+            calendar.early_close(date!(2019 - 02 - 04)),
+            Some(time!(12:00))
+        );
+
+        // No half day on a holiday or a weekend
+        assert_eq!(calendar.early_close(date!(2022 - 12 - 24)), None);
+    }
+
+    // Test to verify the lunar holidays, derived from the Chinese calendar.
+    #[test]
+    fn test_lunar_holidays() {
+        let calendar = HongKongCalendar;
+
+        // Lunar New Year, with the fourth day when one of the first three is a Sunday
+        for date in [
+            date!(2024 - 02 - 12),
+            date!(2024 - 02 - 13),
+            date!(2023 - 01 - 25),
+        ] {
+            assert!(!calendar.is_business_day(date));
+        }
+        assert!(calendar.is_business_day(date!(2024 - 02 - 14)));
+
+        // Lunar New Year's Eve before 2013, when the first day is a Sunday
+        assert!(calendar.is_holiday(date!(2010 - 02 - 13)));
+        assert!(!calendar.is_business_day(date!(2010 - 02 - 16)));
+
+        // Buddha's Birthday (Monday if on a Sunday), Tuen Ng and Chung Yeung
+        assert!(!calendar.is_business_day(date!(2024 - 05 - 15)));
+        assert!(!calendar.is_business_day(date!(2022 - 05 - 09)));
+        assert!(!calendar.is_business_day(date!(2024 - 06 - 10)));
+        assert!(!calendar.is_business_day(date!(2024 - 10 - 11)));
+
+        // the day following Mid-Autumn Festival (October 2nd after National Day)
+        assert!(!calendar.is_business_day(date!(2024 - 09 - 18)));
+        assert!(calendar.is_business_day(date!(2024 - 09 - 17)));
+        assert!(!calendar.is_business_day(date!(2012 - 10 - 02)));
+
+        // the second day following Mid-Autumn Festival, when the day following is a Sunday
+        assert!(calendar.is_holiday(date!(2022 - 09 - 12)));
+        assert!(!calendar.is_holiday(date!(2022 - 09 - 10)));
+        assert!(calendar.is_holiday(date!(2029 - 09 - 24)));
+
+        // years beyond the old hard-coded tables
+        assert!(!calendar.is_business_day(date!(2035 - 02 - 08)));
+        assert!(!calendar.is_business_day(date!(2050 - 01 - 24)));
+    }
+}
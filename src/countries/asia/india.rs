@@ -14,7 +14,11 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use crate::calendar::Calendar;
+use crate::hijri_calendar::{HijriCalendar, IslamicHoliday, IslamicObservance};
+use crate::hindu_calendar::{diwali, dussehra, guru_nanak_jayanti, holi};
+use crate::hindu_calendar::{maha_shivaratri, mahavir_jayanti, rama_navami};
 use crate::utilities::unpack_date;
+use time::macros::date;
 use time::{Date, Month};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -24,6 +28,31 @@ use time::{Date, Month};
 /// India national holiday calendar.
 pub struct IndiaCalendar;
 
+/// Observance of the Islamic holidays in India: usually a day after Saudi Arabia,
+/// with the dates announced by the government where they differ.
+#[rustfmt::skip]
+const ISLAMIC_OBSERVANCE: IslamicObservance = IslamicObservance::new(
+    HijriCalendar::UmmAlQura,
+    1,
+    &[
+        (IslamicHoliday::EidAlFitr, date!(2000 - 01 - 08)),
+        (IslamicHoliday::EidAlFitr, date!(2004 - 11 - 14)),
+        (IslamicHoliday::EidAlFitr, date!(2007 - 10 - 13)),
+        (IslamicHoliday::EidAlFitr, date!(2010 - 09 - 10)),
+        (IslamicHoliday::EidAlFitr, date!(2015 - 07 - 19)),
+        (IslamicHoliday::EidAlFitr, date!(2016 - 07 - 06)),
+        (IslamicHoliday::EidAlFitr, date!(2018 - 06 - 15)),
+        (IslamicHoliday::EidAlAdha, date!(2000 - 03 - 16)),
+        (IslamicHoliday::EidAlAdha, date!(2005 - 01 - 21)),
+        (IslamicHoliday::EidAlAdha, date!(2007 - 12 - 20)),
+        (IslamicHoliday::EidAlAdha, date!(2014 - 10 - 06)),
+        (IslamicHoliday::EidAlAdha, date!(2015 - 09 - 25)),
+        (IslamicHoliday::EidAlAdha, date!(2016 - 09 - 13)),
+        (IslamicHoliday::Ashura,    date!(2021 - 08 - 20)),
+        (IslamicHoliday::Ashura,    date!(2029 - 05 - 25)),
+    ],
+);
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS, METHODS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -34,39 +63,39 @@ impl Calendar for IndiaCalendar {
     }
 
     fn is_holiday(&self, date: Date) -> bool {
-        let (y, m, d, _wd, yd, em) = unpack_date(date, false);
+        let (_y, m, d, _wd, yd, em) = unpack_date(date, false);
 
         if (
             // Republic Day
             (d == 26 && m == Month::January)
             // Mahashivratri
-            || self.is_mahashivratri(y, d, m)
+            || self.is_mahashivratri(date)
             // Holi
-            || self.is_holi(y, d, m)
+            || self.is_holi(date)
             // Good Friday
             || (yd == em - 3)
             // Eid-ul-Fitar
-            || self.is_eid_ul_fitar(y,d,m)
+            || ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::EidAlFitr, date)
             // Rama Navami
-            || self.is_rama_navami(y,d,m)
+            || self.is_rama_navami(date)
             // Mahavir Jayanti
-            || self.is_mahavir_jayanti(y,d,m)
+            || self.is_mahavir_jayanti(date)
             // Maharashtra Day
             || (d == 1 && m == Month::May)
             // Bakri Id
-            || self.is_bakri_id(y,d,m)
-            // Muharram
-            || self.is_muharram(y,d,m)
+            || ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::EidAlAdha, date)
+            // Muharram (Ashura)
+            || ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::Ashura, date)
             // Independence Day
             || (d == 15 && m == Month::August)
             // Gandhi Jayanti
             || (d == 2 && m == Month::October)
             // Dussehra
-            || self.is_dussehra(y,d,m)
+            || self.is_dussehra(date)
             // Diwali
-            || self.is_diwali(y,d,m)
+            || self.is_diwali(date)
             // Gurunanak Jayanti
-            || self.is_gurunanak_jayanti(y,d,m)
+            || self.is_gurunanak_jayanti(date)
             // Christmas
             || (d == 25 && m == Month::December)
         ) {
@@ -78,347 +107,68 @@ impl Calendar for IndiaCalendar {
 }
 
 impl IndiaCalendar {
-    fn is_mahashivratri(&self, year: i32, day: u8, month: Month) -> bool {
+    fn is_mahashivratri(&self, date: Date) -> bool {
         use Month::{February, March};
-
-        matches!(
-            (year, day, month),
-            (2000, 4, March)
-                | (2001, 21, February)
-                | (2002, 12, March)
-                | (2003, 1, March)
-                | (2004, 18, February)
-                | (2005, 8, March)
-                | (2006, 26, February)
-                | (2007, 16, February)
-                | (2008, 6, March)
-                | (2009, 23, February)
-                | (2010, 12, February)
-                | (2011, 2, March)
-                | (2012, 20, February)
-                | (2013, 10, March)
-                | (2014, 27, February)
-                | (2015, 17, February)
-                | (2016, 7, March)
-                | (2017, 24, February)
-                | (2018, 13, February)
-                | (2019, 4, March)
-                | (2020, 21, February)
-                | (2021, 11, March)
-                | (2022, 1, March)
-                | (2023, 18, February)
-                | (2024, 8, March)
-                | (2025, 26, February)
-                | (2026, 15, February)
-                | (2027, 6, March)
-                | (2028, 23, February)
-                | (2029, 11, February)
-        )
-    }
-
-    fn is_holi(&self, year: i32, day: u8, month: Month) -> bool {
-        use Month::March;
-        matches!(
-            (year, day, month),
-            (2000, 20, March)
-                | (2001, 10, March)
-                | (2002, 29, March)
-                | (2003, 18, March)
-                | (2004, 7, March)
-                | (2005, 26, March)
-                | (2006, 15, March)
-                | (2007, 4, March)
-                | (2008, 22, March)
-                | (2009, 11, March)
-                | (2010, 1, March)
-                | (2011, 20, March)
-                | (2012, 8, March)
-                | (2013, 27, March)
-                | (2014, 17, March)
-                | (2015, 6, March)
-                | (2016, 24, March)
-                | (2017, 13, March)
-                | (2018, 2, March)
-                | (2019, 21, March)
-                | (2020, 10, March)
-                | (2021, 29, March)
-                | (2022, 18, March)
-                | (2023, 8, March)
-                | (2024, 25, March)
-                | (2025, 14, March)
-                | (2026, 4, March)
-                | (2027, 22, March)
-                | (2028, 11, March)
-                | (2029, 1, March)
-        )
+        is_hindu_festival(date, &[February, March], maha_shivaratri, &[])
     }
 
-    fn is_eid_ul_fitar(&self, year: i32, day: u8, month: Month) -> bool {
-        matches!(
-            (year, day, month),
-            (2000, 8, Month::January)
-                | (2000, 28, Month::December)
-                | (2001, 17, Month::December)
-                | (2002, 6, Month::December)
-                | (2003, 26, Month::November)
-                | (2004, 14, Month::November)
-                | (2005, 4, Month::November)
-                | (2006, 24, Month::October)
-                | (2007, 13, Month::October)
-                | (2008, 2, Month::October)
-                | (2009, 21, Month::September)
-                | (2010, 10, Month::September)
-                | (2011, 31, Month::August)
-                | (2012, 20, Month::August)
-                | (2013, 9, Month::August)
-                | (2014, 29, Month::July)
-                | (2015, 19, Month::July)
-                | (2016, 6, Month::July)
-                | (2017, 26, Month::June)
-                | (2018, 15, Month::June)
-                | (2019, 5, Month::June)
-                | (2020, 25, Month::May)
-                | (2021, 14, Month::May)
-                | (2022, 3, Month::May)
-                | (2023, 22, Month::April)
-                | (2024, 11, Month::April)
-                | (2025, 31, Month::March)
-                | (2026, 21, Month::March)
-                | (2027, 10, Month::March)
-                | (2028, 27, Month::February)
-                | (2029, 15, Month::February)
-        )
+    fn is_holi(&self, date: Date) -> bool {
+        use Month::{February, March};
+        #[rustfmt::skip]
+        let announced = [date!(2016 - 03 - 24), date!(2023 - 03 - 08), date!(2026 - 03 - 04)];
+        is_hindu_festival(date, &[February, March], holi, &announced)
     }
 
-    fn is_rama_navami(&self, year: i32, day: u8, month: Month) -> bool {
+    fn is_rama_navami(&self, date: Date) -> bool {
         use Month::{April, March};
-        matches!(
-            (year, day, month),
-            (2000, 12, April)
-                | (2001, 2, April)
-                | (2002, 21, April)
-                | (2003, 11, April)
-                | (2004, 30, March)
-                | (2005, 18, April)
-                | (2006, 6, April)
-                | (2007, 26, March)
-                | (2008, 13, April)
-                | (2009, 3, April)
-                | (2010, 24, March)
-                | (2011, 12, April)
-                | (2012, 1, April)
-                | (2013, 19, April)
-                | (2014, 8, April)
-                | (2015, 28, March)
-                | (2016, 15, April)
-                | (2017, 4, April)
-                | (2018, 25, March)
-                | (2019, 13, April)
-                | (2020, 2, April)
-                | (2021, 21, April)
-                | (2022, 10, April)
-                | (2023, 30, March)
-                | (2024, 17, April)
-                | (2025, 6, April)
-                | (2026, 26, March)
-                | (2027, 15, April)
-                | (2028, 3, April)
-                | (2029, 22, April)
-        )
+        is_hindu_festival(date, &[March, April], rama_navami, &[date!(2005 - 04 - 18)])
     }
 
-    fn is_mahavir_jayanti(&self, year: i32, day: u8, month: Month) -> bool {
+    fn is_mahavir_jayanti(&self, date: Date) -> bool {
         use Month::{April, March};
-        matches!(
-            (year, day, month),
-            (2005, 22, April)
-                | (2006, 11, April)
-                | (2007, 31, March)
-                | (2008, 18, April)
-                | (2009, 7, April)
-                | (2010, 28, April)
-                | (2011, 16, April)
-                | (2012, 5, April)
-                | (2013, 24, April)
-                | (2014, 13, April)
-                | (2015, 2, April)
-                | (2016, 20, April)
-                | (2017, 9, April)
-                | (2018, 29, March)
-                | (2019, 17, April)
-                | (2020, 6, April)
-                | (2021, 25, April)
-                | (2022, 14, April)
-                | (2023, 4, April)
-                | (2024, 21, April)
-                | (2025, 10, April)
-        )
+        #[rustfmt::skip]
+        let announced = [date!(2010 - 04 - 28), date!(2016 - 04 - 20)];
+        is_hindu_festival(date, &[March, April], mahavir_jayanti, &announced)
     }
 
-    fn is_bakri_id(&self, year: i32, day: u8, month: Month) -> bool {
-        matches!(
-            (year, day, month),
-            (2000, 16, Month::March)
-                | (2001, 6, Month::March)
-                | (2002, 23, Month::February)
-                | (2003, 12, Month::February)
-                | (2004, 2, Month::February)
-                | (2005, 21, Month::January)
-                | (2006, 11, Month::January)
-                | (2007, 20, Month::December)
-                | (2008, 9, Month::December)
-                | (2009, 28, Month::November)
-                | (2010, 17, Month::November)
-                | (2011, 7, Month::November)
-                | (2012, 27, Month::October)
-                | (2013, 16, Month::October)
-                | (2014, 6, Month::October)
-                | (2015, 25, Month::September)
-                | (2016, 13, Month::September)
-                | (2017, 2, Month::September)
-                | (2018, 22, Month::August)
-                | (2019, 12, Month::August)
-                | (2020, 1, Month::August)
-                | (2021, 21, Month::July)
-                | (2022, 10, Month::July)
-                | (2023, 29, Month::June)
-                | (2024, 17, Month::June)
-                | (2025, 7, Month::June)
-                | (2026, 28, Month::May)
-                | (2027, 17, Month::May)
-                | (2028, 6, Month::May)
-                | (2029, 25, Month::April)
-        )
+    fn is_dussehra(&self, date: Date) -> bool {
+        use Month::{October, September};
+        #[rustfmt::skip]
+        let announced = [date!(2003 - 10 - 05), date!(2019 - 10 - 08), date!(2022 - 10 - 05)];
+        is_hindu_festival(date, &[September, October], dussehra, &announced)
     }
 
-    fn is_muharram(&self, year: i32, day: u8, month: Month) -> bool {
-        matches!(
-            (year, day, month),
-            (2007, 30, Month::January)
-                | (2009, 28, Month::December)
-                | (2010, 17, Month::December)
-                | (2011, 6, Month::December)
-                | (2012, 25, Month::November)
-                | (2013, 14, Month::November)
-                | (2014, 4, Month::November)
-                | (2015, 24, Month::October)
-                | (2016, 12, Month::October)
-                | (2017, 1, Month::October)
-                | (2018, 21, Month::September)
-                | (2019, 10, Month::September)
-                | (2020, 30, Month::August)
-                | (2021, 20, Month::August)
-                | (2022, 9, Month::August)
-                | (2023, 29, Month::July)
-                | (2024, 17, Month::July)
-                | (2025, 6, Month::July)
-                | (2026, 26, Month::June)
-                | (2027, 16, Month::June)
-                | (2028, 4, Month::June)
-                | (2029, 25, Month::May)
-        )
+    fn is_diwali(&self, date: Date) -> bool {
+        use Month::{November, October};
+        is_hindu_festival(date, &[October, November], diwali, &[])
     }
 
-    fn is_dussehra(&self, year: i32, day: u8, month: Month) -> bool {
-        use Month::{October, September};
-        matches!(
-            (year, day, month),
-            (2000, 7, October)
-                | (2001, 26, October)
-                | (2002, 15, October)
-                | (2003, 5, October)
-                | (2004, 22, October)
-                | (2005, 12, October)
-                | (2006, 2, October)
-                | (2007, 21, October)
-                | (2008, 9, October)
-                | (2009, 28, September)
-                | (2010, 17, October)
-                | (2011, 6, October)
-                | (2012, 24, October)
-                | (2013, 13, October)
-                | (2014, 3, October)
-                | (2015, 22, October)
-                | (2016, 11, October)
-                | (2017, 30, September)
-                | (2018, 19, October)
-                | (2019, 8, October)
-                | (2020, 25, October)
-                | (2021, 15, October)
-                | (2022, 5, October)
-                | (2023, 24, October)
-                | (2024, 12, October)
-                | (2025, 2, October)
-                | (2026, 20, October)
-                | (2027, 9, October)
-                | (2028, 27, September)
-                | (2029, 16, October)
+    fn is_gurunanak_jayanti(&self, date: Date) -> bool {
+        use Month::{December, November, October};
+        is_hindu_festival(
+            date,
+            &[October, November, December],
+            guru_nanak_jayanti,
+            &[],
         )
     }
+}
 
-    fn is_diwali(&self, year: i32, day: u8, month: Month) -> bool {
-        use Month::{November, October};
-        matches!(
-            (year, day, month),
-            (2000, 26, October)
-                | (2001, 14, November)
-                | (2002, 4, November)
-                | (2003, 25, October)
-                | (2004, 12, November)
-                | (2005, 1, November)
-                | (2006, 21, October)
-                | (2007, 9, November)
-                | (2008, 28, October)
-                | (2009, 17, October)
-                | (2010, 5, November)
-                | (2011, 26, October)
-                | (2012, 13, November)
-                | (2013, 3, November)
-                | (2014, 23, October)
-                | (2015, 11, November)
-                | (2016, 30, October)
-                | (2017, 19, October)
-                | (2018, 7, November)
-                | (2019, 27, October)
-                | (2020, 14, November)
-                | (2021, 4, November)
-                | (2022, 24, October)
-                | (2023, 12, November)
-                | (2024, 31, October)
-                | (2025, 20, October)
-                | (2026, 8, November)
-                | (2027, 29, October)
-                | (2028, 17, October)
-                | (2029, 5, November)
-        )
+/// Function to check if a date is a Hindu festival: the date announced by the
+/// government for the year if it differs from the computed one, or the computed one.
+fn is_hindu_festival(
+    date: Date,
+    months: &[Month],
+    festival: fn(i32) -> Option<Date>,
+    announced: &[Date],
+) -> bool {
+    if !months.contains(&date.month()) {
+        return false;
     }
 
-    fn is_gurunanak_jayanti(&self, year: i32, day: u8, month: Month) -> bool {
-        use Month::November;
-        matches!(
-            (year, day, month),
-            (2005, 15, November)
-                | (2006, 5, November)
-                | (2007, 24, November)
-                | (2008, 13, November)
-                | (2009, 2, November)
-                | (2010, 21, November)
-                | (2011, 10, November)
-                | (2012, 28, November)
-                | (2013, 17, November)
-                | (2014, 6, November)
-                | (2015, 25, November)
-                | (2016, 14, November)
-                | (2017, 4, November)
-                | (2018, 23, November)
-                | (2019, 12, November)
-                | (2020, 30, November)
-                | (2021, 19, November)
-                | (2022, 8, November)
-                | (2023, 27, November)
-                | (2024, 15, November)
-                | (2025, 5, November)
-        )
+    match announced.iter().find(|day| day.year() == date.year()) {
+        Some(day) => *day == date,
+        None => festival(date.year()) == Some(date),
     }
 }
 
@@ -499,4 +249,34 @@ mod test_india {
         assert!(calendar.is_business_day(regular_day2));
         assert!(calendar.is_business_day(regular_day3));
     }
+
+    // Test to verify that the Islamic holidays are derived from the Hijri calendar.
+    #[test]
+    fn test_islamic_holidays() {
+        let calendar = IndiaCalendar;
+
+        // A day after Saudi Arabia
+        assert!(!calendar.is_business_day(date!(2024 - 04 - 11)));
+        assert!(calendar.is_business_day(date!(2024 - 04 - 10)));
+        assert!(!calendar.is_business_day(date!(2009 - 01 - 08)));
+        assert!(!calendar.is_business_day(date!(2030 - 02 - 05)));
+
+        // Announced date, a day after the computed one
+        assert!(!calendar.is_business_day(date!(2014 - 10 - 06)));
+        assert!(calendar.is_business_day(date!(2014 - 10 - 07)));
+    }
+
+    // Test to verify the Hindu festivals beyond the announced dates.
+    #[test]
+    fn test_hindu_festivals() {
+        let calendar = IndiaCalendar;
+
+        // Diwali 2031 and Dussehra 2033, beyond the announced dates
+        assert!(!calendar.is_business_day(date!(2031 - 11 - 14)));
+        assert!(!calendar.is_business_day(date!(2033 - 10 - 03)));
+
+        // Announced date, a day after the computed one
+        assert!(!calendar.is_business_day(date!(2026 - 03 - 04)));
+        assert!(calendar.is_business_day(date!(2026 - 03 - 03)));
+    }
 }
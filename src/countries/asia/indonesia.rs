@@ -14,7 +14,10 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use crate::calendar::Calendar;
+use crate::chinese_calendar::chinese_new_year;
+use crate::hijri_calendar::{HijriCalendar, IslamicHoliday, IslamicObservance};
 use crate::utilities::unpack_date;
+use time::macros::date;
 use time::{Date, Month};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -24,6 +27,44 @@ use time::{Date, Month};
 /// Indonesia national holiday calendar.
 pub struct IndonesiaCalendar;
 
+/// Observance of the Islamic holidays in Indonesia, following the tabular calendar,
+/// with the dates set by the government where they differ.
+#[rustfmt::skip]
+const ISLAMIC_OBSERVANCE: IslamicObservance = IslamicObservance::new(
+    HijriCalendar::Tabular,
+    0,
+    &[
+        (IslamicHoliday::IslamicNewYear, date!(2010 - 12 - 07)),
+        (IslamicHoliday::IslamicNewYear, date!(2015 - 10 - 14)),
+        (IslamicHoliday::IslamicNewYear, date!(2016 - 10 - 02)),
+        (IslamicHoliday::IslamicNewYear, date!(2017 - 09 - 21)),
+        (IslamicHoliday::IslamicNewYear, date!(2018 - 09 - 11)),
+        (IslamicHoliday::IslamicNewYear, date!(2024 - 07 - 07)),
+        (IslamicHoliday::IslamicNewYear, date!(2026 - 06 - 16)),
+        (IslamicHoliday::MawlidAnNabi,   date!(2006 - 04 - 10)),
+        (IslamicHoliday::MawlidAnNabi,   date!(2011 - 02 - 15)),
+        (IslamicHoliday::MawlidAnNabi,   date!(2018 - 11 - 20)),
+        (IslamicHoliday::MawlidAnNabi,   date!(2019 - 11 - 09)),
+        (IslamicHoliday::MawlidAnNabi,   date!(2023 - 09 - 28)),
+        (IslamicHoliday::MawlidAnNabi,   date!(2024 - 09 - 16)),
+        (IslamicHoliday::MawlidAnNabi,   date!(2026 - 08 - 25)),
+        (IslamicHoliday::IsraMiraj,      date!(2016 - 05 - 06)),
+        (IslamicHoliday::IsraMiraj,      date!(2018 - 04 - 14)),
+        (IslamicHoliday::IsraMiraj,      date!(2022 - 02 - 28)),
+        (IslamicHoliday::IsraMiraj,      date!(2024 - 02 - 08)),
+        (IslamicHoliday::EidAlFitr,      date!(2008 - 10 - 01)),
+        (IslamicHoliday::EidAlFitr,      date!(2009 - 09 - 20)),
+        (IslamicHoliday::EidAlFitr,      date!(2014 - 07 - 28)),
+        (IslamicHoliday::EidAlFitr,      date!(2015 - 07 - 17)),
+        (IslamicHoliday::EidAlFitr,      date!(2016 - 07 - 06)),
+        (IslamicHoliday::EidAlFitr,      date!(2017 - 06 - 25)),
+        (IslamicHoliday::EidAlFitr,      date!(2022 - 05 - 02)),
+        (IslamicHoliday::EidAlFitr,      date!(2026 - 03 - 21)),
+        (IslamicHoliday::EidAlAdha,      date!(2019 - 08 - 11)),
+        (IslamicHoliday::EidAlAdha,      date!(2025 - 06 - 06)),
+    ],
+);
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS, METHODS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -40,7 +81,7 @@ impl Calendar for IndonesiaCalendar {
             // New Year's Day
             (d == 1 && m == Month::January)
             // Ascension of the Prophet Muhammad
-            || self.is_ascension_day_of_prophet_muhammad(y,d,m)
+            || ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::IsraMiraj, date)
             // Lunar New Year
             || self.is_lunar_new_year(y,d,m)
             // Hindu New year
@@ -48,7 +89,7 @@ impl Calendar for IndonesiaCalendar {
             // Good Friday
             || (yd == em - 3)
             // Eid-ul-Fitar
-            || self.is_eid_ul_fitar(y,d,m)
+            || self.is_eid_ul_fitar(date)
             // Labor Day
             || (d == 1 && m == Month::May)
             // Ascension Day of Jesus Christ
@@ -61,13 +102,13 @@ impl Calendar for IndonesiaCalendar {
             // Pancasila Day
             || (d == 1 && m == Month::June)
             // Eid-ul-Adha
-            || self.is_eid_ul_adha(y,d,m)
+            || self.is_eid_ul_adha(date)
             // Muharram
-            || self.is_muharram(y,d,m)
+            || self.is_muharram(date)
             // Independence Day
             || (d==17 && m==Month::August)
             // Birth of Prophet Muhammad
-            || self.is_birth_of_prophet_muhammad(y,d,m)
+            || self.is_birth_of_prophet_muhammad(date)
             // Christmas
             || (d == 25 && m==Month::December)
             // Boxing Day
@@ -81,68 +122,20 @@ impl Calendar for IndonesiaCalendar {
 }
 
 impl IndonesiaCalendar {
-    fn is_ascension_day_of_prophet_muhammad(&self, year: i32, day: u8, month: Month) -> bool {
-        matches!(
-            (year, day, month),
-            (2000, 26, Month::October)
-                | (2001, 15, Month::October)
-                | (2002, 4, Month::October)
-                | (2003, 24, Month::September)
-                | (2004, 12, Month::September)
-                | (2005, 1, Month::September)
-                | (2006, 22, Month::August)
-                | (2007, 11, Month::August)
-                | (2008, 31, Month::July)
-                | (2009, 20, Month::July)
-                | (2010, 9, Month::July)
-                | (2011, 29, Month::June)
-                | (2012, 17, Month::June)
-                | (2013, 6, Month::June)
-                | (2014, 27, Month::May)
-                | (2015, 16, Month::May)
-                | (2016, 6, Month::May)
-                | (2017, 24, Month::April)
-                | (2018, 14, Month::April)
-                | (2019, 3, Month::April)
-                | (2020, 22, Month::March)
-                | (2021, 11, Month::March)
-                | (2022, 28, Month::February)
-                | (2023, 18, Month::February)
-                | (2024, 8, Month::February)
-        )
-    }
-
     fn is_lunar_new_year(&self, year: i32, day: u8, month: Month) -> bool {
-        use Month::{February, January};
-        matches!(
-            (year, day, month),
-            (2000, 5, February)
-                | (2001, 24, January)
-                | (2002, 12, February)
-                | (2003, 1, February)
-                | (2004, 22, January)
-                | (2005, 9, February)
-                | (2006, 30, January)
-                | (2007, 19, February)
-                | (2008, 7, February)
-                | (2009, 26, January)
-                | (2010, 15, February)
-                | (2011, 3, February)
-                | (2012, 23, January)
-                | (2013, 11, February)
-                | (2014, 31, January)
-                | (2015, 19, February)
-                | (2016, 8, February)
-                | (2017, 28, January)
-                | (2018, 16, February)
-                | (2019, 5, February)
-                | (2020, 25, January)
-                | (2021, 12, February)
-                | (2022, 1, February)
-                | (2023, 23, January)
-                | (2024, 9, February)
-                | (2025, 29, January)
-        )
+        let date = Date::from_calendar_date(year, month, day).unwrap();
+
+        // Chinese New Year, or the day observed in its place
+        chinese_new_year(year) == Some(date)
+            || matches!(
+                (year, day, month),
+                (2006, 30, Month::January)
+                    | (2007, 19, Month::February)
+                    | (2010, 15, Month::February)
+                    | (2013, 11, Month::February)
+                    | (2023, 23, Month::January)
+                    | (2024, 9, Month::February)
+            )
     }
 
     fn is_hindu_new_year(&self, year: i32, day: u8, month: Month) -> bool {
@@ -173,59 +166,53 @@ impl IndonesiaCalendar {
         )
     }
 
-    fn is_eid_ul_fitar(&self, year: i32, day: u8, month: Month) -> bool {
-        matches!(
-            (year, day, month),
-            (2006, 23, Month::October)
-                | (2007, 12, Month::October)
-                | (2008, 29, Month::September)
-                | (2008, 30, Month::September)
-                | (2010, 9, Month::September)
-                | (2015, 16, Month::July)
-                | (2016, 4, Month::July)
-                | (2016, 5, Month::July)
-                | (2018, 11, Month::June)
-                | (2018, 12, Month::June)
-                | (2019, 3, Month::June)
-                | (2019, 4, Month::June)
-                | (2021, 12, Month::May)
-                | (2022, 29, Month::April)
-                | (2023, 19, Month::April)
-                | (2023, 20, Month::April)
-                | (2024, 8, Month::April)
-                | (2024, 9, Month::April)
-                | (2006, 26, Month::October)
-                | (2007, 15, Month::October)
-                | (2008, 3, Month::October)
-                | (2009, 23, Month::September)
-                | (2010, 13, Month::September)
-                | (2014, 30, Month::July)
-                | (2015, 20, Month::July)
-                | (2016, 8, Month::July)
-                | (2018, 18, Month::June)
-                | (2018, 13, Month::June)
-                | (2019, 7, Month::June)
-                | (2019, 6, Month::June)
-                | (2019, 5, Month::June)
-                | (2020, 25, Month::May)
-                | (2020, 24, Month::May)
-                | (2021, 14, Month::May)
-                | (2021, 17, Month::May)
-                | (2021, 13, Month::May)
-                | (2022, 2, Month::May)
-                | (2022, 3, Month::May)
-                | (2022, 4, Month::May)
-                | (2023, 21, Month::April)
-                | (2023, 24, Month::April)
-                | (2023, 22, Month::April)
-                | (2023, 23, Month::April)
-                | (2024, 12, Month::April)
-                | (2024, 15, Month::April)
-                | (2024, 11, Month::April)
-                | (2024, 10, Month::April)
-                | (2025, 1, Month::April)
-                | (2025, 2, Month::April)
-        )
+    fn is_eid_ul_fitar(&self, date: Date) -> bool {
+        let (y, m, d, _, _, _) = unpack_date(date, false);
+
+        // Idul Fitri (two days), or the collective leave days around it
+        ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::EidAlFitr, date)
+            || date
+                .previous_day()
+                .is_some_and(|day| ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::EidAlFitr, day))
+            || matches!(
+                (y, d, m),
+                (2006, 23, Month::October)
+                    | (2006, 26, Month::October)
+                    | (2007, 12, Month::October)
+                    | (2007, 15, Month::October)
+                    | (2008, 29, Month::September)
+                    | (2008, 30, Month::September)
+                    | (2008, 3, Month::October)
+                    | (2009, 23, Month::September)
+                    | (2010, 9, Month::September)
+                    | (2010, 13, Month::September)
+                    | (2014, 30, Month::July)
+                    | (2015, 16, Month::July)
+                    | (2015, 20, Month::July)
+                    | (2016, 4, Month::July)
+                    | (2016, 5, Month::July)
+                    | (2016, 8, Month::July)
+                    | (2018, 11, Month::June)
+                    | (2018, 12, Month::June)
+                    | (2018, 13, Month::June)
+                    | (2018, 18, Month::June)
+                    | (2019, 3, Month::June)
+                    | (2019, 4, Month::June)
+                    | (2019, 7, Month::June)
+                    | (2021, 12, Month::May)
+                    | (2021, 17, Month::May)
+                    | (2022, 29, Month::April)
+                    | (2022, 4, Month::May)
+                    | (2023, 19, Month::April)
+                    | (2023, 20, Month::April)
+                    | (2023, 21, Month::April)
+                    | (2023, 24, Month::April)
+                    | (2024, 8, Month::April)
+                    | (2024, 9, Month::April)
+                    | (2024, 12, Month::April)
+                    | (2024, 15, Month::April)
+                    | (2025, 2, Month::April)
+            )
     }
 
     fn is_vesak_day(&self, year: i32, day: u8, month: Month) -> bool {
@@ -257,81 +244,22 @@ impl IndonesiaCalendar {
         )
     }
 
-    fn is_eid_ul_adha(&self, year: i32, day: u8, month: Month) -> bool {
-        use Month::{August, July, June};
-        matches!(
-            (year, day, month),
-            (2019, 11, August)
-                | (2020, 31, July)
-                | (2021, 20, July)
-                | (2022, 10, July)
-                | (2023, 29, June)
-                | (2024, 17, June)
-                | (2024, 18, June)
-                | (2025, 7, June)
-        )
+    fn is_eid_ul_adha(&self, date: Date) -> bool {
+        // Idul Adha, or the collective leave day after it
+        ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::EidAlAdha, date)
+            || date == date!(2024 - 06 - 18)
     }
 
-    fn is_muharram(&self, year: i32, day: u8, month: Month) -> bool {
-        matches!(
-            (year, day, month),
-            (2000, 6, Month::April)
-                | (2001, 26, Month::March)
-                | (2002, 15, Month::March)
-                | (2003, 5, Month::March)
-                | (2004, 22, Month::February)
-                | (2005, 10, Month::February)
-                | (2006, 31, Month::January)
-                | (2007, 20, Month::January)
-                | (2008, 10, Month::January)
-                | (2008, 29, Month::December)
-                | (2009, 18, Month::December)
-                | (2010, 7, Month::December)
-                | (2011, 27, Month::November)
-                | (2012, 15, Month::November)
-                | (2013, 5, Month::November)
-                | (2014, 25, Month::October)
-                | (2015, 14, Month::October)
-                | (2016, 2, Month::October)
-                | (2017, 21, Month::September)
-                | (2018, 11, Month::September)
-                | (2019, 1, Month::September)
-                | (2020, 20, Month::August)
-                | (2021, 10, Month::August)
-                | (2021, 11, Month::August)
-                | (2022, 30, Month::July)
-                | (2023, 19, Month::July)
-                | (2024, 7, Month::July)
-                | (2025, 27, Month::June)
-        )
+    fn is_muharram(&self, date: Date) -> bool {
+        // Islamic New Year, or the day it was moved to in 2021
+        ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::IslamicNewYear, date)
+            || date == date!(2021 - 08 - 11)
     }
 
-    fn is_birth_of_prophet_muhammad(&self, year: i32, day: u8, month: Month) -> bool {
-        matches!(
-            (year, day, month),
-            (2006, 10, Month::April)
-                | (2007, 31, Month::March)
-                | (2008, 20, Month::March)
-                | (2009, 9, Month::March)
-                | (2010, 26, Month::February)
-                | (2011, 15, Month::February)
-                | (2012, 5, Month::February)
-                | (2013, 24, Month::January)
-                | (2014, 14, Month::January)
-                | (2015, 3, Month::January)
-                | (2015, 24, Month::December)
-                | (2016, 12, Month::December)
-                | (2017, 1, Month::December)
-                | (2018, 20, Month::November)
-                | (2019, 9, Month::November)
-                | (2020, 29, Month::October)
-                | (2021, 19, Month::October)
-                | (2021, 20, Month::October)
-                | (2022, 8, Month::October)
-                | (2023, 28, Month::September)
-                | (2024, 15, Month::September)
-                | (2025, 5, Month::September)
-        )
+    fn is_birth_of_prophet_muhammad(&self, date: Date) -> bool {
+        // Mawlid, or the day it was moved to in 2021
+        ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::MawlidAnNabi, date)
+            || date == date!(2021 - 10 - 20)
     }
 }
 
@@ -418,4 +346,52 @@ mod test_indonesia {
         assert!(calendar.is_business_day(regular_day2));
         assert!(calendar.is_business_day(regular_day3));
     }
+
+    // Test to verify that Chinese New Year is derived from the Chinese calendar.
+    #[test]
+    fn test_lunar_new_year() {
+        let calendar = IndonesiaCalendar;
+
+        assert!(!calendar.is_business_day(date!(2028 - 01 - 26)));
+        assert!(!calendar.is_business_day(date!(2033 - 01 - 31)));
+        assert!(calendar.is_business_day(date!(2028 - 01 - 27)));
+
+        // the Mondays observed in place of a Chinese New Year on a weekend
+        for date in [
+            date!(2006 - 01 - 30),
+            date!(2007 - 02 - 19),
+            date!(2010 - 02 - 15),
+            date!(2013 - 02 - 11),
+            date!(2023 - 01 - 23),
+        ] {
+            assert!(calendar.is_holiday(date));
+        }
+    }
+
+    // Test to verify that the Islamic holidays are derived from the Hijri calendar.
+    #[test]
+    fn test_islamic_holidays() {
+        let calendar = IndonesiaCalendar;
+
+        // Computed dates, outside the announced ones
+        assert!(!calendar.is_business_day(date!(2025 - 01 - 27)));
+        assert!(!calendar.is_business_day(date!(2028 - 05 - 05)));
+
+        // Both days of Idul Fitri
+        assert!(!calendar.is_business_day(date!(2013 - 08 - 08)));
+        assert!(!calendar.is_business_day(date!(2013 - 08 - 09)));
+
+        // Announced date, a day before the computed one
+        assert!(!calendar.is_business_day(date!(2022 - 05 - 02)));
+        assert!(calendar.is_business_day(date!(2022 - 05 - 05)));
+
+        // Idul Adha 2025 was observed on the Friday, not the computed Saturday
+        assert!(!calendar.is_business_day(date!(2025 - 06 - 06)));
+
+        // Announced dates for 2024 to 2026
+        assert!(!calendar.is_business_day(date!(2024 - 09 - 16)));
+        assert!(!calendar.is_business_day(date!(2026 - 06 - 16)));
+        assert!(!calendar.is_business_day(date!(2026 - 08 - 25)));
+        assert!(calendar.is_business_day(date!(2026 - 08 - 26)));
+    }
 }
@@ -0,0 +1,200 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::calendar::Calendar;
+use crate::hebrew_calendar::{jewish_holiday, HebrewDate, HebrewMonth, JewishHoliday};
+use crate::utilities::unpack_date;
+use time::macros::date;
+use time::{Date, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Israel calendar, following the Tel Aviv Stock Exchange (TASE).
+///
+/// The holidays are the Jewish holidays and their eves, Independence Day
+/// and election days. The weekend is Friday and Saturday until the TASE
+/// moved to Monday - Friday trading in January 2026, and Saturday and Sunday since.
+pub struct IsraelCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// First day of Monday - Friday trading on the TASE.
+const MONDAY_TO_FRIDAY_START: Date = date!(2026 - 01 - 05);
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for IsraelCalendar {
+    fn name(&self) -> &'static str {
+        "Israel"
+    }
+
+    fn is_weekend(&self, date: Date) -> bool {
+        match date < MONDAY_TO_FRIDAY_START {
+            true => matches!(date.weekday(), Weekday::Friday | Weekday::Saturday),
+            false => matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday),
+        }
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let hebrew = HebrewDate::from_gregorian(date);
+
+        if (
+            // Jewish holidays and their eves
+            self.is_jewish_holiday(hebrew)
+            // Purim
+            || self.is_moved_holiday(date, JewishHoliday::Purim)
+            // Independence Day
+            || self.is_moved_holiday(date, JewishHoliday::YomHaatzmaut)
+            // Tisha B'Av
+            || self.is_moved_holiday(date, JewishHoliday::TishaBav)
+            // Knesset elections
+            || self.is_election_day(date)
+        ) {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl IsraelCalendar {
+    fn is_jewish_holiday(&self, hebrew: HebrewDate) -> bool {
+        use HebrewMonth::{Elul, Nisan, Sivan, Tishri};
+
+        matches!(
+            (hebrew.month, hebrew.day),
+            // Eve of Rosh Hashanah, Rosh Hashanah
+            (Elul, 29) | (Tishri, 1 | 2)
+            // Eve of Yom Kippur, Yom Kippur
+            | (Tishri, 9 | 10)
+            // Eve of Sukkot, Sukkot
+            | (Tishri, 14 | 15)
+            // Eve of Simchat Torah, Simchat Torah
+            | (Tishri, 21 | 22)
+            // Eve of Pesach, Pesach
+            | (Nisan, 14 | 15)
+            // Eve of the seventh day of Pesach, seventh day of Pesach
+            | (Nisan, 20 | 21)
+            // Eve of Shavuot, Shavuot
+            | (Sivan, 5 | 6)
+        )
+    }
+
+    /// Function to check holidays which depend on the year or the day of the week.
+    fn is_moved_holiday(&self, date: Date, holiday: JewishHoliday) -> bool {
+        jewish_holiday(holiday, date.year()) == Some(date)
+    }
+
+    fn is_election_day(&self, date: Date) -> bool {
+        let (y, m, d, _, _, _) = unpack_date(date, false);
+
+        matches!(
+            (y, d, m),
+            (2013, 22, Month::January)
+                | (2015, 17, Month::March)
+                | (2019, 9, Month::April)
+                | (2019, 17, Month::September)
+                | (2020, 2, Month::March)
+                | (2021, 23, Month::March)
+                | (2022, 1, Month::November)
+        )
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_israel {
+    use super::*;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = IsraelCalendar;
+        assert_eq!(calendar.name(), "Israel");
+    }
+
+    // Test to verify the weekend, before and after the move to Monday - Friday trading.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = IsraelCalendar;
+
+        // Friday and Saturday, Sunday a business day
+        assert!(!calendar.is_business_day(date!(2025 - 11 - 14)));
+        assert!(!calendar.is_business_day(date!(2025 - 11 - 15)));
+        assert!(calendar.is_business_day(date!(2025 - 11 - 16)));
+
+        // Saturday and Sunday from January 2026, Friday a business day
+        assert!(calendar.is_business_day(date!(2026 - 01 - 09)));
+        assert!(!calendar.is_business_day(date!(2026 - 01 - 10)));
+        assert!(!calendar.is_business_day(date!(2026 - 01 - 11)));
+        assert!(calendar.is_business_day(date!(2026 - 01 - 12)));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for public holidays.
+    #[test]
+    fn test_is_public_holiday() {
+        let calendar = IsraelCalendar;
+        let purim = date!(2024 - 03 - 24);
+        let pesach_eve = date!(2024 - 04 - 22);
+        let pesach = date!(2024 - 04 - 23);
+        let seventh_day_of_pesach = date!(2024 - 04 - 29);
+        let independence_day = date!(2024 - 05 - 14);
+        let shavuot_eve = date!(2024 - 06 - 11);
+        let shavuot = date!(2024 - 06 - 12);
+        let tisha_bav = date!(2024 - 08 - 13);
+        let rosh_hashanah_eve = date!(2024 - 10 - 02);
+        let rosh_hashanah = date!(2024 - 10 - 03);
+        let yom_kippur_eve = date!(2023 - 09 - 24);
+        let sukkot = date!(2024 - 10 - 17);
+        let simchat_torah = date!(2024 - 10 - 24);
+        let election_day = date!(2022 - 11 - 01);
+
+        assert!(!calendar.is_business_day(purim));
+        assert!(!calendar.is_business_day(pesach_eve));
+        assert!(!calendar.is_business_day(pesach));
+        assert!(!calendar.is_business_day(seventh_day_of_pesach));
+        assert!(!calendar.is_business_day(independence_day));
+        assert!(!calendar.is_business_day(shavuot_eve));
+        assert!(!calendar.is_business_day(shavuot));
+        assert!(!calendar.is_business_day(tisha_bav));
+        assert!(!calendar.is_business_day(rosh_hashanah_eve));
+        assert!(!calendar.is_business_day(rosh_hashanah));
+        assert!(!calendar.is_business_day(yom_kippur_eve));
+        assert!(!calendar.is_business_day(sukkot));
+        assert!(!calendar.is_business_day(simchat_torah));
+        assert!(!calendar.is_business_day(election_day));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for regular business days.
+    #[test]
+    fn test_is_regular_business_day() {
+        let calendar = IsraelCalendar;
+
+        // Hanukkah, Memorial Day, the intermediate days of Pesach, and an ordinary Monday
+        assert!(calendar.is_business_day(date!(2024 - 12 - 26)));
+        assert!(calendar.is_business_day(date!(2024 - 05 - 13)));
+        assert!(calendar.is_business_day(date!(2024 - 04 - 25)));
+        assert!(calendar.is_business_day(date!(2024 - 11 - 04)));
+    }
+}
@@ -0,0 +1,334 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::astronomy::{solar_term_date, SolarTerm};
+use crate::calendar::{Calendar, EarlyClose};
+use crate::utilities::unpack_date;
+use time::macros::{date, offset};
+use time::{Date, Month, Time, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Japan national holiday calendar.
+///
+/// The national holidays ("kokumin no shukujitsu"), the substitute holidays
+/// for national holidays on a Sunday, and the citizen's holidays between two
+/// national holidays.
+pub struct JapanCalendar;
+
+/// Japan Exchange Group (JPX) calendar, for the Tokyo Stock Exchange.
+///
+/// The national holidays, and the year-end closure from 31 December to 3 January.
+#[derive(Default)]
+pub struct JpxCalendar;
+
+/// Unscheduled JPX full closures.
+#[rustfmt::skip]
+const JPX_SPECIAL_CLOSURES: [Date; 1] = [
+    date!(2020 - 10 - 01), // arrowhead system failure
+];
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for JapanCalendar {
+    fn name(&self) -> &'static str {
+        "Japan"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.is_national_holiday(date)
+            || self.is_substitute_holiday(date)
+            || self.is_citizens_holiday(date)
+    }
+}
+
+impl Calendar for JpxCalendar {
+    fn name(&self) -> &'static str {
+        "Japan Exchange Group"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let (_, m, d, _, _, _) = unpack_date(date, false);
+
+        JapanCalendar.is_holiday(date)
+            // Year-end closure
+            || ((d == 31 && m == Month::December) || (d <= 3 && m == Month::January))
+            || JPX_SPECIAL_CLOSURES.contains(&date)
+    }
+}
+
+impl EarlyClose for JpxCalendar {
+    /// The TSE has no shortened trading sessions.
+    fn early_close(&self, _date: Date) -> Option<Time> {
+        None
+    }
+}
+
+impl JapanCalendar {
+    /// Function to check if the date is a national holiday, under the
+    /// Act on National Holidays (1948) and its amendments.
+    fn is_national_holiday(&self, date: Date) -> bool {
+        let (y, m, d, wd, _, _) = unpack_date(date, false);
+        let is_monday = wd == Weekday::Monday;
+
+        // New Year's Day
+        (d == 1 && m == Month::January)
+            // Coming of Age Day (second Monday of January since 2000)
+            || ((d == 15 && y < 2000 || (8..=14).contains(&d) && is_monday && y >= 2000)
+                && m == Month::January)
+            // National Foundation Day
+            || (d == 11 && m == Month::February && y >= 1967)
+            // Emperor's Birthday
+            || (d == 23 && m == Month::February && y >= 2020)
+            || (d == 23 && m == Month::December && (1989..=2018).contains(&y))
+            // Vernal Equinox Day
+            || (m == Month::March && self.is_equinox(date, SolarTerm::MarchEquinox))
+            // Emperor's Birthday, Greenery Day (1989 - 2006), Showa Day (since 2007)
+            || (d == 29 && m == Month::April)
+            // Constitution Memorial Day
+            || (d == 3 && m == Month::May)
+            // Greenery Day (since 2007)
+            || (d == 4 && m == Month::May && y >= 2007)
+            // Children's Day
+            || (d == 5 && m == Month::May)
+            // Marine Day (third Monday of July since 2003)
+            || ((d == 20 && (1996..=2002).contains(&y)
+                || (15..=21).contains(&d) && is_monday && y >= 2003 && y != 2020 && y != 2021)
+                && m == Month::July)
+            // Mountain Day
+            || (d == 11 && m == Month::August && y >= 2016 && y != 2020 && y != 2021)
+            // Respect for the Aged Day (third Monday of September since 2003)
+            || ((d == 15 && (1966..=2002).contains(&y)
+                || (15..=21).contains(&d) && is_monday && y >= 2003)
+                && m == Month::September)
+            // Autumnal Equinox Day
+            || (m == Month::September && self.is_equinox(date, SolarTerm::SeptemberEquinox))
+            // Health and Sports Day (second Monday of October since 2000)
+            || ((d == 10 && (1966..=1999).contains(&y)
+                || (8..=14).contains(&d) && is_monday && y >= 2000 && y != 2020 && y != 2021)
+                && m == Month::October)
+            // Culture Day
+            || (d == 3 && m == Month::November)
+            // Labour Thanksgiving Day
+            || (d == 23 && m == Month::November)
+            // Holidays moved for the Tokyo Olympics, and the imperial ceremonies
+            || self.is_special_holiday(date)
+    }
+
+    /// Function to check if the date is the equinox, in Japan time.
+    fn is_equinox(&self, date: Date, equinox: SolarTerm) -> bool {
+        solar_term_date(date.year(), equinox, offset!(+9)) == date
+    }
+
+    fn is_special_holiday(&self, date: Date) -> bool {
+        let (y, m, d, _, _, _) = unpack_date(date, false);
+
+        matches!(
+            (y, d, m),
+            // Wedding of Crown Prince Akihito
+            (1959, 10, Month::April)
+            // Funeral of Emperor Showa
+            | (1989, 24, Month::February)
+            // Enthronement ceremony of Emperor Akihito
+            | (1990, 12, Month::November)
+            // Wedding of Crown Prince Naruhito
+            | (1993, 9, Month::June)
+            // Accession and enthronement ceremony of Emperor Naruhito
+            | (2019, 1, Month::May)
+            | (2019, 22, Month::October)
+            // Marine Day, Sports Day and Mountain Day, moved for the Tokyo Olympics
+            | (2020, 23, Month::July)
+            | (2020, 24, Month::July)
+            | (2020, 10, Month::August)
+            | (2021, 22, Month::July)
+            | (2021, 23, Month::July)
+            | (2021, 8, Month::August)
+        )
+    }
+
+    /// Function to check if the date is a substitute holiday ("furikae kyujitsu"):
+    /// the first day which is not a national holiday after a national holiday on a Sunday
+    /// (since 2007; before, only the Monday after a national holiday on a Sunday, since 1973).
+    fn is_substitute_holiday(&self, date: Date) -> bool {
+        if date < date!(1973 - 04 - 12) || self.is_national_holiday(date) {
+            return false;
+        }
+
+        let mut day = date;
+
+        while let Some(previous) = day.previous_day() {
+            if !self.is_national_holiday(previous) {
+                return false;
+            }
+            if previous.weekday() == Weekday::Sunday {
+                return true;
+            }
+            if date.year() < 2007 {
+                return false;
+            }
+
+            day = previous;
+        }
+
+        false
+    }
+
+    /// Function to check if the date is a citizen's holiday ("kokumin no kyujitsu"):
+    /// a day between two national holidays (since 1985).
+    fn is_citizens_holiday(&self, date: Date) -> bool {
+        date >= date!(1985 - 12 - 27)
+            && !self.is_national_holiday(date)
+            && date
+                .previous_day()
+                .is_some_and(|day| self.is_national_holiday(day))
+            && date
+                .next_day()
+                .is_some_and(|day| self.is_national_holiday(day))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_japan {
+    use super::*;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        assert_eq!(JapanCalendar.name(), "Japan");
+        assert_eq!(JpxCalendar.name(), "Japan Exchange Group");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = JapanCalendar;
+        let sat = date!(2024 - 03 - 09);
+        let sun = date!(2024 - 03 - 10);
+        assert!(!calendar.is_business_day(sat));
+        assert!(!calendar.is_business_day(sun));
+    }
+
+    // Test to verify the full list of holidays of some years.
+    #[test]
+    fn test_holidays() {
+        let calendar = JapanCalendar;
+
+        // Imperial transition, with citizen's holidays on 30 April and 2 May
+        assert_eq!(
+            calendar.all_holidays_between(date!(2019 - 01 - 01), date!(2019 - 12 - 31)),
+            vec![
+                date!(2019 - 01 - 01),
+                date!(2019 - 01 - 14),
+                date!(2019 - 02 - 11),
+                date!(2019 - 03 - 21),
+                date!(2019 - 04 - 29),
+                date!(2019 - 04 - 30),
+                date!(2019 - 05 - 01),
+                date!(2019 - 05 - 02),
+                date!(2019 - 05 - 03),
+                date!(2019 - 05 - 04),
+                date!(2019 - 05 - 05),
+                date!(2019 - 05 - 06),
+                date!(2019 - 07 - 15),
+                date!(2019 - 08 - 11),
+                date!(2019 - 08 - 12),
+                date!(2019 - 09 - 16),
+                date!(2019 - 09 - 23),
+                date!(2019 - 10 - 14),
+                date!(2019 - 10 - 22),
+                date!(2019 - 11 - 03),
+                date!(2019 - 11 - 04),
+                date!(2019 - 11 - 23),
+            ]
+        );
+
+        // Tokyo Olympics, and a substitute holiday after two days of holidays
+        assert_eq!(
+            calendar.all_holidays_between(date!(2020 - 01 - 01), date!(2020 - 12 - 31)),
+            vec![
+                date!(2020 - 01 - 01),
+                date!(2020 - 01 - 13),
+                date!(2020 - 02 - 11),
+                date!(2020 - 02 - 23),
+                date!(2020 - 02 - 24),
+                date!(2020 - 03 - 20),
+                date!(2020 - 04 - 29),
+                date!(2020 - 05 - 03),
+                date!(2020 - 05 - 04),
+                date!(2020 - 05 - 05),
+                date!(2020 - 05 - 06),
+                date!(2020 - 07 - 23),
+                date!(2020 - 07 - 24),
+                date!(2020 - 08 - 10),
+                date!(2020 - 09 - 21),
+                date!(2020 - 09 - 22),
+                date!(2020 - 11 - 03),
+                date!(2020 - 11 - 23),
+            ]
+        );
+    }
+
+    // Test to verify the Happy Monday, citizen's and substitute holidays.
+    #[test]
+    fn test_moving_holidays() {
+        let calendar = JapanCalendar;
+
+        // Happy Monday holidays, and the fixed dates before
+        assert!(calendar.is_holiday(date!(2024 - 01 - 08)));
+        assert!(calendar.is_holiday(date!(1999 - 01 - 15)));
+        assert!(calendar.is_holiday(date!(2024 - 07 - 15)));
+        assert!(calendar.is_holiday(date!(2024 - 09 - 16)));
+        assert!(calendar.is_holiday(date!(2024 - 10 - 14)));
+        assert!(!calendar.is_holiday(date!(2021 - 10 - 11)));
+
+        // Citizen's holidays in the "silver weeks" of 2009 and 2015
+        assert!(calendar.is_holiday(date!(2009 - 09 - 22)));
+        assert!(calendar.is_holiday(date!(2015 - 09 - 22)));
+
+        // Substitute holidays
+        assert!(calendar.is_holiday(date!(2024 - 02 - 12)));
+        assert!(calendar.is_holiday(date!(2024 - 09 - 23)));
+        assert!(calendar.is_holiday(date!(2021 - 08 - 09)));
+
+        // Equinoxes
+        assert!(calendar.is_holiday(date!(2024 - 03 - 20)));
+        assert!(calendar.is_holiday(date!(2024 - 09 - 22)));
+        assert!(calendar.is_holiday(date!(2012 - 09 - 22)));
+        assert!(!calendar.is_holiday(date!(2012 - 09 - 23)));
+    }
+
+    // Test to verify the JPX year-end closure.
+    #[test]
+    fn test_jpx() {
+        let calendar = JpxCalendar;
+
+        assert!(!calendar.is_business_day(date!(2024 - 12 - 31)));
+        assert!(!calendar.is_business_day(date!(2025 - 01 - 02)));
+        assert!(!calendar.is_business_day(date!(2025 - 01 - 03)));
+        assert!(calendar.is_business_day(date!(2025 - 01 - 06)));
+        assert!(JapanCalendar.is_business_day(date!(2025 - 01 - 02)));
+
+        assert!(!calendar.is_business_day(date!(2020 - 10 - 01)));
+        assert!(!calendar.is_business_day(date!(2024 - 11 - 04)));
+    }
+}
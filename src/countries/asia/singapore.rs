@@ -0,0 +1,272 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::calendar::Calendar;
+use crate::chinese_calendar::{chinese_new_year, LunarDate};
+use crate::hijri_calendar::{HijriCalendar, IslamicHoliday, IslamicObservance};
+use crate::hindu_calendar::naraka_chaturdashi;
+use crate::utilities::unpack_date;
+use time::macros::date;
+use time::{Date, Duration, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Singapore public holiday calendar.
+///
+/// The public holidays under the Holidays Act, with the holidays falling on
+/// a Sunday observed on the next day which is not a public holiday, and polling days.
+pub struct SingaporeCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Observance of Hari Raya Puasa and Hari Raya Haji in Singapore, following the
+/// tabular calendar, with the dates announced by MUIS where they differ.
+#[rustfmt::skip]
+const ISLAMIC_OBSERVANCE: IslamicObservance = IslamicObservance::new(
+    HijriCalendar::Tabular,
+    0,
+    &[
+        (IslamicHoliday::EidAlFitr, date!(2008 - 10 - 01)),
+        (IslamicHoliday::EidAlFitr, date!(2009 - 09 - 20)),
+        (IslamicHoliday::EidAlFitr, date!(2011 - 08 - 30)),
+        (IslamicHoliday::EidAlFitr, date!(2014 - 07 - 28)),
+        (IslamicHoliday::EidAlFitr, date!(2015 - 07 - 17)),
+        (IslamicHoliday::EidAlFitr, date!(2016 - 07 - 06)),
+        (IslamicHoliday::EidAlFitr, date!(2017 - 06 - 25)),
+        (IslamicHoliday::EidAlFitr, date!(2026 - 03 - 21)),
+        (IslamicHoliday::EidAlAdha, date!(2008 - 12 - 08)),
+        (IslamicHoliday::EidAlAdha, date!(2009 - 11 - 27)),
+        (IslamicHoliday::EidAlAdha, date!(2011 - 11 - 06)),
+        (IslamicHoliday::EidAlAdha, date!(2016 - 09 - 12)),
+        (IslamicHoliday::EidAlAdha, date!(2017 - 09 - 01)),
+        (IslamicHoliday::EidAlAdha, date!(2019 - 08 - 11)),
+    ],
+);
+
+/// Deepavali, as announced where it differs from Naraka Chaturdashi computed at New Delhi.
+#[rustfmt::skip]
+const DEEPAVALI: [Date; 4] = [
+    date!(2005 - 11 - 01),
+    date!(2006 - 10 - 21),
+    date!(2011 - 10 - 26),
+    date!(2021 - 11 - 04),
+];
+
+/// Polling days and other one-off public holidays.
+#[rustfmt::skip]
+const SPECIAL_HOLIDAYS: [Date; 9] = [
+    date!(2001 - 11 - 03), // general election
+    date!(2006 - 05 - 06), // general election
+    date!(2011 - 05 - 07), // general election
+    date!(2011 - 08 - 27), // presidential election
+    date!(2015 - 08 - 07), // SG50
+    date!(2015 - 09 - 11), // general election
+    date!(2020 - 07 - 10), // general election
+    date!(2023 - 09 - 01), // presidential election
+    date!(2025 - 05 - 03), // general election
+];
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for SingaporeCalendar {
+    fn name(&self) -> &'static str {
+        "Singapore"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.is_public_holiday(date)
+            || self.is_observed_holiday(date)
+            || SPECIAL_HOLIDAYS.contains(&date)
+    }
+}
+
+impl SingaporeCalendar {
+    fn is_public_holiday(&self, date: Date) -> bool {
+        let (_, m, d, _, yd, em) = unpack_date(date, false);
+
+        // New Year's Day
+        (d == 1 && m == Month::January)
+            // Chinese New Year (two days)
+            || self.is_chinese_new_year(date)
+            // Good Friday
+            || (yd == em - 3)
+            // Labour Day
+            || (d == 1 && m == Month::May)
+            // Hari Raya Puasa
+            || ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::EidAlFitr, date)
+            // Vesak Day
+            || self.is_vesak_day(date)
+            // National Day
+            || (d == 9 && m == Month::August)
+            // Hari Raya Haji
+            || ISLAMIC_OBSERVANCE.is_holiday(IslamicHoliday::EidAlAdha, date)
+            // Deepavali
+            || self.is_deepavali(date)
+            // Christmas Day
+            || (d == 25 && m == Month::December)
+    }
+
+    fn is_chinese_new_year(&self, date: Date) -> bool {
+        chinese_new_year(date.year())
+            .is_some_and(|new_year| date == new_year || date == new_year + Duration::DAY)
+    }
+
+    /// Vesak Day: the full moon (15th day) of the fourth Chinese lunar month.
+    fn is_vesak_day(&self, date: Date) -> bool {
+        matches!(date.month(), Month::April | Month::May | Month::June)
+            && LunarDate::new(date.year(), 4, 15, false).map(|lunar| lunar.to_gregorian())
+                == Some(date)
+    }
+
+    /// Deepavali: Naraka Chaturdashi, or the announced date of the year.
+    fn is_deepavali(&self, date: Date) -> bool {
+        if !matches!(date.month(), Month::October | Month::November) {
+            return false;
+        }
+
+        match DEEPAVALI.iter().find(|day| day.year() == date.year()) {
+            Some(day) => *day == date,
+            None => naraka_chaturdashi(date.year()) == Some(date),
+        }
+    }
+
+    /// Function to check if the date is the day a public holiday falling on a Sunday
+    /// is observed: the next day which is not a public holiday.
+    fn is_observed_holiday(&self, date: Date) -> bool {
+        if self.is_public_holiday(date) {
+            return false;
+        }
+
+        let mut day = date;
+
+        while let Some(previous) = day.previous_day() {
+            if !self.is_public_holiday(previous) {
+                return false;
+            }
+            if previous.weekday() == Weekday::Sunday {
+                return true;
+            }
+
+            day = previous;
+        }
+
+        false
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_singapore {
+    use super::*;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = SingaporeCalendar;
+        assert_eq!(calendar.name(), "Singapore");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = SingaporeCalendar;
+        let sat = date!(2024 - 03 - 09);
+        let sun = date!(2024 - 03 - 10);
+        assert!(!calendar.is_business_day(sat));
+        assert!(!calendar.is_business_day(sun));
+    }
+
+    // Test to verify the full list of holidays of some years.
+    #[test]
+    fn test_holidays() {
+        let calendar = SingaporeCalendar;
+
+        // Chinese New Year on a Sunday, and Deepavali on a Sunday
+        assert_eq!(
+            calendar.all_holidays_between(date!(2023 - 01 - 01), date!(2023 - 12 - 31)),
+            vec![
+                date!(2023 - 01 - 01),
+                date!(2023 - 01 - 02),
+                date!(2023 - 01 - 22),
+                date!(2023 - 01 - 23),
+                date!(2023 - 01 - 24),
+                date!(2023 - 04 - 07),
+                date!(2023 - 04 - 22),
+                date!(2023 - 05 - 01),
+                date!(2023 - 06 - 02),
+                date!(2023 - 06 - 29),
+                date!(2023 - 08 - 09),
+                date!(2023 - 09 - 01),
+                date!(2023 - 11 - 12),
+                date!(2023 - 11 - 13),
+                date!(2023 - 12 - 25),
+            ]
+        );
+
+        assert_eq!(
+            calendar.all_holidays_between(date!(2024 - 01 - 01), date!(2024 - 12 - 31)),
+            vec![
+                date!(2024 - 01 - 01),
+                date!(2024 - 02 - 10),
+                date!(2024 - 02 - 11),
+                date!(2024 - 02 - 12),
+                date!(2024 - 03 - 29),
+                date!(2024 - 04 - 10),
+                date!(2024 - 05 - 01),
+                date!(2024 - 05 - 22),
+                date!(2024 - 06 - 17),
+                date!(2024 - 08 - 09),
+                date!(2024 - 10 - 31),
+                date!(2024 - 12 - 25),
+            ]
+        );
+    }
+
+    // Test to verify the announced dates of the Islamic and Hindu holidays.
+    #[test]
+    fn test_announced_holidays() {
+        let calendar = SingaporeCalendar;
+
+        assert!(calendar.is_holiday(date!(2017 - 06 - 25)));
+        assert!(calendar.is_holiday(date!(2017 - 06 - 26)));
+        assert!(!calendar.is_holiday(date!(2017 - 06 - 27)));
+        assert!(calendar.is_holiday(date!(2019 - 08 - 12)));
+        assert!(!calendar.is_holiday(date!(2019 - 08 - 13)));
+
+        assert!(calendar.is_holiday(date!(2021 - 11 - 04)));
+        assert!(!calendar.is_holiday(date!(2021 - 11 - 03)));
+        assert!(calendar.is_holiday(date!(2018 - 11 - 06)));
+        assert!(!calendar.is_holiday(date!(2018 - 11 - 07)));
+
+        // Hari Raya Puasa and Hari Raya Haji as announced by MUIS for 2024 to 2026
+        assert!(calendar.is_holiday(date!(2024 - 04 - 10)));
+        assert!(calendar.is_holiday(date!(2024 - 06 - 17)));
+        assert!(calendar.is_holiday(date!(2025 - 03 - 31)));
+        assert!(calendar.is_holiday(date!(2025 - 06 - 07)));
+        assert!(!calendar.is_holiday(date!(2025 - 06 - 06)));
+        assert!(calendar.is_holiday(date!(2026 - 03 - 21)));
+        assert!(!calendar.is_holiday(date!(2026 - 03 - 20)));
+        assert!(calendar.is_holiday(date!(2026 - 05 - 27)));
+    }
+}
@@ -0,0 +1,442 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::calendar::Calendar;
+use crate::korean_calendar::{chuseok, korean_buddhas_birthday, seollal};
+use crate::utilities::unpack_date;
+use time::macros::date;
+use time::{Date, Duration, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// South Korea public holiday calendar.
+///
+/// The public holidays, with Seollal and Chuseok computed from the Korean
+/// lunisolar calendar, election days, temporary holidays, and the substitute
+/// holidays for public holidays on a weekend or on another public holiday.
+pub struct SouthKoreaCalendar;
+
+/// Korea Exchange (KRX) calendar.
+///
+/// The public holidays, Labour Day, and the year-end closure on the last
+/// business day of the year.
+pub struct KrxCalendar;
+
+/// Substitute holiday rule of a public holiday.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Substitution {
+    /// No substitute holiday.
+    Never,
+    /// A substitute holiday if the holiday falls on a Sunday or another public holiday.
+    Sunday,
+    /// A substitute holiday if the holiday falls on a weekend or another public holiday.
+    Weekend,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Presidential, National Assembly and local election days.
+#[rustfmt::skip]
+const ELECTION_DAYS: [Date; 28] = [
+    date!(1987 - 12 - 16), // presidential
+    date!(1988 - 04 - 26), // national assembly
+    date!(1992 - 03 - 24), // national assembly
+    date!(1992 - 12 - 18), // presidential
+    date!(1995 - 06 - 27), // local
+    date!(1996 - 04 - 11), // national assembly
+    date!(1997 - 12 - 18), // presidential
+    date!(1998 - 06 - 04), // local
+    date!(2000 - 04 - 13), // national assembly
+    date!(2002 - 06 - 13), // local
+    date!(2002 - 12 - 19), // presidential
+    date!(2004 - 04 - 15), // national assembly
+    date!(2006 - 05 - 31), // local
+    date!(2007 - 12 - 19), // presidential
+    date!(2008 - 04 - 09), // national assembly
+    date!(2010 - 06 - 02), // local
+    date!(2012 - 04 - 11), // national assembly
+    date!(2012 - 12 - 19), // presidential
+    date!(2014 - 06 - 04), // local
+    date!(2016 - 04 - 13), // national assembly
+    date!(2017 - 05 - 09), // presidential
+    date!(2018 - 06 - 13), // local
+    date!(2020 - 04 - 15), // national assembly
+    date!(2022 - 03 - 09), // presidential
+    date!(2022 - 06 - 01), // local
+    date!(2024 - 04 - 10), // national assembly
+    date!(2025 - 06 - 03), // presidential
+    date!(2026 - 06 - 03), // local
+];
+
+/// Temporary public holidays, designated by the government.
+#[rustfmt::skip]
+const TEMPORARY_HOLIDAYS: [Date; 8] = [
+    date!(2002 - 07 - 01), // FIFA World Cup
+    date!(2015 - 08 - 14), // 70th anniversary of the liberation
+    date!(2016 - 05 - 06), // bridge day
+    date!(2017 - 10 - 02), // bridge day
+    date!(2020 - 08 - 17), // bridge day
+    date!(2023 - 10 - 02), // bridge day
+    date!(2024 - 10 - 01), // Armed Forces Day
+    date!(2025 - 01 - 27), // bridge day
+];
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for SouthKoreaCalendar {
+    fn name(&self) -> &'static str {
+        "South Korea"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.is_regular_holiday(date) || self.is_substitute_holiday(date)
+    }
+}
+
+impl Calendar for KrxCalendar {
+    fn name(&self) -> &'static str {
+        "Korea Exchange"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let (_, m, d, _, _, _) = unpack_date(date, false);
+
+        SouthKoreaCalendar.is_holiday(date)
+            // Labour Day
+            || (d == 1 && m == Month::May)
+            // Year-end closure
+            || self.is_year_end_closure(date)
+    }
+}
+
+impl KrxCalendar {
+    /// Function to check if the date is the last business day of the year.
+    fn is_year_end_closure(&self, date: Date) -> bool {
+        if date.month() != Month::December || !SouthKoreaCalendar.is_business_day(date) {
+            return false;
+        }
+
+        let mut day = date;
+
+        while let Some(next) = day.next_day().filter(|next| next.year() == date.year()) {
+            if SouthKoreaCalendar.is_business_day(next) {
+                return false;
+            }
+            day = next;
+        }
+
+        true
+    }
+}
+
+impl SouthKoreaCalendar {
+    /// Function to check if the date is a public holiday, an election day or a temporary holiday.
+    fn is_regular_holiday(&self, date: Date) -> bool {
+        !self.public_holidays(date).is_empty()
+            || ELECTION_DAYS.contains(&date)
+            || TEMPORARY_HOLIDAYS.contains(&date)
+    }
+
+    /// Function to list the public holidays falling on the date, with their substitute holiday rule,
+    /// under the Regulations on Holidays of Government Offices and their amendments.
+    fn public_holidays(&self, date: Date) -> Vec<Substitution> {
+        use Substitution::{Never, Sunday, Weekend};
+
+        let (y, m, d, _, _, _) = unpack_date(date, false);
+        let mut holidays = Vec::new();
+
+        // Substitute holidays for Seollal, Chuseok and Children's Day since 2014,
+        // for the national days since August 2021, and for Buddha's Birthday and Christmas since 2023
+        let lunar = if y >= 2014 { Sunday } else { Never };
+        let children = if y >= 2014 { Weekend } else { Never };
+        let national = if y >= 2021 { Weekend } else { Never };
+        let religious = if y >= 2023 { Weekend } else { Never };
+
+        let mut holiday = |is_holiday: bool, substitution: Substitution| {
+            if is_holiday {
+                holidays.push(substitution);
+            }
+        };
+
+        // New Year's Day (and the following days until 1998)
+        holiday(d == 1 && m == Month::January, Never);
+        holiday(d == 2 && m == Month::January && y <= 1998, Never);
+        holiday(d == 3 && m == Month::January && y <= 1989, Never);
+        // Seollal (three days since 1989)
+        holiday(self.is_seollal(date), lunar);
+        // Independence Movement Day
+        holiday(d == 1 && m == Month::March, national);
+        // Arbor Day (until 2005)
+        holiday(d == 5 && m == Month::April && y <= 2005, Never);
+        // Children's Day (since 1975)
+        holiday(d == 5 && m == Month::May && y >= 1975, children);
+        // Buddha's Birthday (since 1975)
+        holiday(y >= 1975 && self.is_buddhas_birthday(date), religious);
+        // Memorial Day
+        holiday(d == 6 && m == Month::June, Never);
+        // Constitution Day (until 2007)
+        holiday(d == 17 && m == Month::July && y <= 2007, Never);
+        // Liberation Day
+        holiday(d == 15 && m == Month::August, national);
+        // Chuseok (three days since 1989)
+        holiday(self.is_chuseok(date), lunar);
+        // Armed Forces Day (until 1990)
+        holiday(d == 1 && m == Month::October && y <= 1990, Never);
+        // National Foundation Day
+        holiday(d == 3 && m == Month::October, national);
+        // Hangul Day (until 1990, and since 2013)
+        holiday(
+            d == 9 && m == Month::October && (y <= 1990 || y >= 2013),
+            national,
+        );
+        // United Nations Day (until 1975)
+        holiday(d == 24 && m == Month::October && y <= 1975, Never);
+        // Christmas Day
+        holiday(d == 25 && m == Month::December, religious);
+
+        holidays
+    }
+
+    /// Function to check if the date is Seollal, its eve or the day after
+    /// (only Seollal from 1985, as Folk Day, to 1988).
+    fn is_seollal(&self, date: Date) -> bool {
+        if !matches!(date.month(), Month::January | Month::February) || date.year() < 1985 {
+            return false;
+        }
+
+        seollal(date.year()).is_some_and(|seollal| match date.year() >= 1989 {
+            true => (date - seollal).whole_days().abs() <= 1,
+            false => date == seollal,
+        })
+    }
+
+    /// Function to check if the date is Chuseok, its eve or the day after
+    /// (only Chuseok and the day after from 1986 to 1988).
+    fn is_chuseok(&self, date: Date) -> bool {
+        if !matches!(date.month(), Month::September | Month::October) {
+            return false;
+        }
+
+        chuseok(date.year()).is_some_and(|chuseok| match date.year() {
+            ..=1985 => date == chuseok,
+            1986..=1988 => date == chuseok || date == chuseok + Duration::DAY,
+            _ => (date - chuseok).whole_days().abs() <= 1,
+        })
+    }
+
+    fn is_buddhas_birthday(&self, date: Date) -> bool {
+        matches!(date.month(), Month::April | Month::May)
+            && korean_buddhas_birthday(date.year()) == Some(date)
+    }
+
+    /// Function to get the number of days lost to substitute on a date: the public holidays
+    /// with a substitute holiday rule on a weekend, or on another public holiday.
+    fn lost_days(&self, date: Date) -> usize {
+        let holidays = self.public_holidays(date);
+        let weekday = date.weekday();
+
+        let substitutable = holidays
+            .iter()
+            .filter(|&&substitution| match substitution {
+                Substitution::Never => false,
+                Substitution::Sunday => weekday != Weekday::Saturday,
+                Substitution::Weekend => true,
+            })
+            .count();
+
+        // On a weekday, one of the holidays is observed on the day itself
+        let observed = usize::from(!self.is_weekend(date));
+
+        substitutable.min(holidays.len().saturating_sub(observed))
+    }
+
+    /// Function to check if the date is a substitute holiday ("daechae gonghyuil"):
+    /// the first days which are neither a weekend nor a holiday after the days lost.
+    fn is_substitute_holiday(&self, date: Date) -> bool {
+        if date < date!(2014 - 01 - 01) || self.is_weekend(date) || self.is_regular_holiday(date) {
+            return false;
+        }
+
+        // Substitute holidays follow the days lost within a few days
+        let mut pending = 0;
+        let mut day = date - Duration::days(14);
+
+        while day < date {
+            if self.is_weekend(day) || self.is_regular_holiday(day) {
+                pending += self.lost_days(day);
+            } else {
+                pending = pending.saturating_sub(1);
+            }
+            day += Duration::DAY;
+        }
+
+        pending > 0
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_south_korea {
+    use super::*;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        assert_eq!(SouthKoreaCalendar.name(), "South Korea");
+        assert_eq!(KrxCalendar.name(), "Korea Exchange");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = SouthKoreaCalendar;
+        let sat = date!(2024 - 03 - 09);
+        let sun = date!(2024 - 03 - 10);
+        assert!(!calendar.is_business_day(sat));
+        assert!(!calendar.is_business_day(sun));
+    }
+
+    // Test to verify the full list of holidays of some years.
+    #[test]
+    fn test_holidays() {
+        let calendar = SouthKoreaCalendar;
+
+        // Substitute holidays for Seollal and Children's Day on a Sunday
+        assert_eq!(
+            calendar.all_holidays_between(date!(2024 - 01 - 01), date!(2024 - 12 - 31)),
+            vec![
+                date!(2024 - 01 - 01),
+                date!(2024 - 02 - 09),
+                date!(2024 - 02 - 10),
+                date!(2024 - 02 - 11),
+                date!(2024 - 02 - 12),
+                date!(2024 - 03 - 01),
+                date!(2024 - 04 - 10),
+                date!(2024 - 05 - 05),
+                date!(2024 - 05 - 06),
+                date!(2024 - 05 - 15),
+                date!(2024 - 06 - 06),
+                date!(2024 - 08 - 15),
+                date!(2024 - 09 - 16),
+                date!(2024 - 09 - 17),
+                date!(2024 - 09 - 18),
+                date!(2024 - 10 - 01),
+                date!(2024 - 10 - 03),
+                date!(2024 - 10 - 09),
+                date!(2024 - 12 - 25),
+            ]
+        );
+
+        // Children's Day on Buddha's Birthday, and Chuseok on a Sunday
+        assert_eq!(
+            calendar.all_holidays_between(date!(2025 - 01 - 01), date!(2025 - 12 - 31)),
+            vec![
+                date!(2025 - 01 - 01),
+                date!(2025 - 01 - 27),
+                date!(2025 - 01 - 28),
+                date!(2025 - 01 - 29),
+                date!(2025 - 01 - 30),
+                date!(2025 - 03 - 01),
+                date!(2025 - 03 - 03),
+                date!(2025 - 05 - 05),
+                date!(2025 - 05 - 06),
+                date!(2025 - 06 - 03),
+                date!(2025 - 06 - 06),
+                date!(2025 - 08 - 15),
+                date!(2025 - 10 - 03),
+                date!(2025 - 10 - 05),
+                date!(2025 - 10 - 06),
+                date!(2025 - 10 - 07),
+                date!(2025 - 10 - 08),
+                date!(2025 - 10 - 09),
+                date!(2025 - 12 - 25),
+            ]
+        );
+    }
+
+    // Test to verify the substitute holidays, and their expansion over the years.
+    #[test]
+    fn test_substitute_holidays() {
+        let calendar = SouthKoreaCalendar;
+
+        // Seollal and Chuseok on a Sunday, or on National Foundation Day
+        assert!(calendar.is_holiday(date!(2023 - 01 - 24)));
+        assert!(calendar.is_holiday(date!(2014 - 09 - 10)));
+        assert!(calendar.is_holiday(date!(2017 - 10 - 06)));
+
+        // Seollal on a Saturday
+        assert!(!calendar.is_holiday(date!(2021 - 02 - 15)));
+
+        // Children's Day on a Saturday
+        assert!(calendar.is_holiday(date!(2018 - 05 - 07)));
+
+        // Liberation Day on a Sunday: since 2021
+        assert!(!calendar.is_holiday(date!(2010 - 08 - 16)));
+        assert!(calendar.is_holiday(date!(2021 - 08 - 16)));
+        assert!(calendar.is_holiday(date!(2021 - 10 - 11)));
+
+        // Buddha's Birthday and Christmas on a weekend: since 2023
+        assert!(!calendar.is_holiday(date!(2022 - 12 - 26)));
+        assert!(calendar.is_holiday(date!(2023 - 05 - 29)));
+    }
+
+    // Test to verify the historical holidays.
+    #[test]
+    fn test_historical_holidays() {
+        let calendar = SouthKoreaCalendar;
+
+        // Arbor Day, Constitution Day, Armed Forces Day and Hangul Day
+        assert!(calendar.is_holiday(date!(2005 - 04 - 05)));
+        assert!(!calendar.is_holiday(date!(2006 - 04 - 05)));
+        assert!(calendar.is_holiday(date!(2007 - 07 - 17)));
+        assert!(!calendar.is_holiday(date!(2008 - 07 - 17)));
+        assert!(calendar.is_holiday(date!(1990 - 10 - 01)));
+        assert!(!calendar.is_holiday(date!(2012 - 10 - 09)));
+
+        // Seollal and Chuseok, a day later than in China in 1997
+        assert!(calendar.is_holiday(date!(1997 - 02 - 09)));
+        assert!(!calendar.is_holiday(date!(1997 - 02 - 06)));
+        assert!(calendar.is_holiday(date!(2007 - 09 - 24)));
+
+        // Election days
+        assert!(calendar.is_holiday(date!(2017 - 05 - 09)));
+        assert!(calendar.is_holiday(date!(2022 - 06 - 01)));
+    }
+
+    // Test to verify the KRX Labour Day and year-end closure.
+    #[test]
+    fn test_krx() {
+        let calendar = KrxCalendar;
+
+        assert!(!calendar.is_business_day(date!(2024 - 05 - 01)));
+        assert!(SouthKoreaCalendar.is_business_day(date!(2024 - 05 - 01)));
+
+        // Last business day of the year, before a weekend
+        assert!(!calendar.is_business_day(date!(2024 - 12 - 31)));
+        assert!(calendar.is_business_day(date!(2024 - 12 - 30)));
+        assert!(!calendar.is_business_day(date!(2022 - 12 - 30)));
+        assert!(calendar.is_business_day(date!(2022 - 12 - 29)));
+        assert!(calendar.is_business_day(date!(2025 - 01 - 02)));
+    }
+}
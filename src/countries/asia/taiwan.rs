@@ -0,0 +1,315 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::astronomy::{solar_term_date, SolarTerm};
+use crate::calendar::Calendar;
+use crate::chinese_calendar::{chinese_new_year, dragon_boat_festival, mid_autumn_festival};
+use time::macros::{date, offset};
+use time::{Date, Duration, Month, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Taiwan calendar, following the office calendar of the Directorate-General
+/// of Personnel Administration (DGPA), which the Taiwan Stock Exchange follows.
+///
+/// The national holidays, their observed days, and the bridge holidays with the
+/// Saturdays they are made up on: these make-up Saturdays are business days.
+pub struct TaiwanCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Bridge holidays (彈性放假), and the Saturdays they are made up on (補行上班).
+#[rustfmt::skip]
+const BRIDGE_HOLIDAYS: [(Date, Date); 28] = [
+    (date!(2012 - 12 - 31), date!(2012 - 12 - 22)),
+    (date!(2013 - 02 - 15), date!(2013 - 02 - 23)),
+    (date!(2013 - 09 - 20), date!(2013 - 09 - 14)),
+    (date!(2015 - 01 - 02), date!(2014 - 12 - 27)),
+    (date!(2016 - 02 - 12), date!(2016 - 01 - 30)),
+    (date!(2016 - 06 - 10), date!(2016 - 06 - 04)),
+    (date!(2016 - 09 - 16), date!(2016 - 09 - 10)),
+    (date!(2017 - 02 - 27), date!(2017 - 02 - 18)),
+    (date!(2017 - 05 - 29), date!(2017 - 06 - 03)),
+    (date!(2017 - 10 - 09), date!(2017 - 09 - 30)),
+    (date!(2018 - 04 - 06), date!(2018 - 03 - 31)),
+    (date!(2018 - 12 - 31), date!(2018 - 12 - 22)),
+    (date!(2019 - 02 - 08), date!(2019 - 01 - 19)),
+    (date!(2019 - 03 - 01), date!(2019 - 02 - 23)),
+    (date!(2019 - 10 - 11), date!(2019 - 10 - 05)),
+    (date!(2020 - 06 - 26), date!(2020 - 06 - 20)),
+    (date!(2020 - 10 - 02), date!(2020 - 09 - 26)),
+    (date!(2021 - 02 - 10), date!(2021 - 02 - 20)),
+    (date!(2021 - 09 - 20), date!(2021 - 09 - 11)),
+    (date!(2022 - 02 - 04), date!(2022 - 01 - 22)),
+    (date!(2023 - 01 - 20), date!(2023 - 01 - 07)),
+    (date!(2023 - 01 - 27), date!(2023 - 02 - 04)),
+    (date!(2023 - 02 - 27), date!(2023 - 02 - 18)),
+    (date!(2023 - 04 - 03), date!(2023 - 03 - 25)),
+    (date!(2023 - 06 - 23), date!(2023 - 06 - 17)),
+    (date!(2023 - 10 - 09), date!(2023 - 09 - 23)),
+    (date!(2024 - 02 - 08), date!(2024 - 02 - 17)),
+    (date!(2025 - 01 - 27), date!(2025 - 02 - 08)),
+];
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for TaiwanCalendar {
+    fn name(&self) -> &'static str {
+        "Taiwan"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let year = date.year();
+
+        self.lunar_new_year_holidays(year).contains(&date)
+            || self.observed_holidays(year).contains(&date)
+            // New Year's Day of the next year, on a Saturday
+            || (date.month() == Month::December && self.observed_holidays(year + 1).contains(&date))
+            || BRIDGE_HOLIDAYS.iter().any(|(bridge, _)| *bridge == date)
+    }
+
+    /// The Saturdays on which a bridge holiday is made up.
+    fn is_working_weekend(&self, date: Date) -> bool {
+        BRIDGE_HOLIDAYS.iter().any(|(_, make_up)| *make_up == date)
+    }
+}
+
+impl TaiwanCalendar {
+    /// Function to list the national holidays of a year (other than the Lunar New Year).
+    fn national_holidays(&self, year: i32) -> Vec<Date> {
+        let day = |month: Month, day: u8| Date::from_calendar_date(year, month, day).ok();
+        let tomb_sweeping_day = solar_term_date(year, SolarTerm::PureBrightness, offset!(+8));
+
+        // Children's Day, the day before Tomb Sweeping Day if they fall on the same day
+        // (the day after if it is a Thursday)
+        let childrens_day = day(Month::April, 4).map(|date| match date == tomb_sweeping_day {
+            true if date.weekday() == Weekday::Thursday => date + Duration::DAY,
+            true => date - Duration::DAY,
+            false => date,
+        });
+
+        [
+            // Founding Day of the Republic of China
+            day(Month::January, 1),
+            // Peace Memorial Day (since 1997)
+            day(Month::February, 28).filter(|_| year >= 1997),
+            // Children's Day (since 2011)
+            childrens_day.filter(|_| year >= 2011),
+            // Tomb Sweeping Day
+            Some(tomb_sweeping_day),
+            // Labour Day (since 2026)
+            day(Month::May, 1).filter(|_| year >= 2026),
+            // Dragon Boat Festival
+            dragon_boat_festival(year),
+            // Teacher's Day (since 2025)
+            day(Month::September, 28).filter(|_| year >= 2025),
+            // Mid-Autumn Festival
+            mid_autumn_festival(year),
+            // National Day
+            day(Month::October, 10),
+            // Taiwan Retrocession Day (since 2025)
+            day(Month::October, 25).filter(|_| year >= 2025),
+            // Constitution Day (since 2025)
+            day(Month::December, 25).filter(|_| year >= 2025),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Function to list the observed national holidays of a year (other than the Lunar New Year):
+    /// since 2013, a holiday on a Saturday is observed on the Friday before,
+    /// and a holiday on a Sunday on the Monday after (or the next free days).
+    fn observed_holidays(&self, year: i32) -> Vec<Date> {
+        let holidays = self.national_holidays(year);
+
+        if year < 2013 {
+            return holidays;
+        }
+
+        let mut observed: Vec<Date> = holidays
+            .iter()
+            .copied()
+            .filter(|date| !self.is_weekend(*date))
+            .collect();
+
+        for &holiday in holidays.iter().filter(|date| self.is_weekend(**date)) {
+            let step = match holiday.weekday() {
+                Weekday::Saturday => -Duration::DAY,
+                _ => Duration::DAY,
+            };
+
+            let mut day = holiday + step;
+            while self.is_weekend(day) || observed.contains(&day) {
+                day += step;
+            }
+
+            observed.push(holiday);
+            observed.push(day);
+        }
+
+        observed
+    }
+
+    /// Function to list the Lunar New Year holidays of a year: the eve and the first three days
+    /// (and the day before the eve, since 2026), with the days falling on a weekend
+    /// made up on the following working days (since 2001).
+    fn lunar_new_year_holidays(&self, year: i32) -> Vec<Date> {
+        let Some(new_year) = chinese_new_year(year) else {
+            return vec![];
+        };
+
+        let first = match year >= 2026 {
+            true => new_year - Duration::days(2),
+            false => new_year - Duration::DAY,
+        };
+        let last = new_year + Duration::days(2);
+
+        let mut holidays: Vec<Date> = (0..=(last - first).whole_days())
+            .map(|days| first + Duration::days(days))
+            .collect();
+
+        if year >= 2001 {
+            let weekend_days = holidays
+                .iter()
+                .filter(|date| self.is_weekend(**date))
+                .count();
+            let mut day = last;
+
+            for _ in 0..weekend_days {
+                day += Duration::DAY;
+                while self.is_weekend(day) {
+                    day += Duration::DAY;
+                }
+                holidays.push(day);
+            }
+        }
+
+        holidays
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_taiwan {
+    use super::*;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = TaiwanCalendar;
+        assert_eq!(calendar.name(), "Taiwan");
+    }
+
+    // Test to verify the weekends, and the make-up Saturdays which are business days.
+    #[test]
+    fn test_is_working_weekend() {
+        let calendar = TaiwanCalendar;
+
+        assert!(!calendar.is_business_day(date!(2024 - 03 - 09)));
+        assert!(!calendar.is_business_day(date!(2024 - 03 - 10)));
+
+        assert!(calendar.is_weekend(date!(2024 - 02 - 17)));
+        assert!(calendar.is_working_weekend(date!(2024 - 02 - 17)));
+        assert!(calendar.is_business_day(date!(2024 - 02 - 17)));
+        assert!(calendar.is_business_day(date!(2023 - 01 - 07)));
+        assert!(calendar.is_business_day(date!(2014 - 12 - 27)));
+        assert!(!calendar.is_business_day(date!(2024 - 02 - 18)));
+    }
+
+    // Test to verify the full list of holidays of some years.
+    #[test]
+    fn test_holidays() {
+        let calendar = TaiwanCalendar;
+
+        // Lunar New Year on a Sunday, and six bridge holidays
+        assert_eq!(
+            calendar.all_holidays_between(date!(2023 - 01 - 01), date!(2023 - 12 - 31)),
+            vec![
+                date!(2023 - 01 - 01),
+                date!(2023 - 01 - 02),
+                date!(2023 - 01 - 20),
+                date!(2023 - 01 - 21),
+                date!(2023 - 01 - 22),
+                date!(2023 - 01 - 23),
+                date!(2023 - 01 - 24),
+                date!(2023 - 01 - 25),
+                date!(2023 - 01 - 26),
+                date!(2023 - 01 - 27),
+                date!(2023 - 02 - 27),
+                date!(2023 - 02 - 28),
+                date!(2023 - 04 - 03),
+                date!(2023 - 04 - 04),
+                date!(2023 - 04 - 05),
+                date!(2023 - 06 - 22),
+                date!(2023 - 06 - 23),
+                date!(2023 - 09 - 29),
+                date!(2023 - 10 - 09),
+                date!(2023 - 10 - 10),
+            ]
+        );
+
+        // Children's Day and Tomb Sweeping Day on the same Thursday
+        assert_eq!(
+            calendar.all_holidays_between(date!(2024 - 01 - 01), date!(2024 - 12 - 31)),
+            vec![
+                date!(2024 - 01 - 01),
+                date!(2024 - 02 - 08),
+                date!(2024 - 02 - 09),
+                date!(2024 - 02 - 10),
+                date!(2024 - 02 - 11),
+                date!(2024 - 02 - 12),
+                date!(2024 - 02 - 13),
+                date!(2024 - 02 - 14),
+                date!(2024 - 02 - 28),
+                date!(2024 - 04 - 04),
+                date!(2024 - 04 - 05),
+                date!(2024 - 06 - 10),
+                date!(2024 - 09 - 17),
+                date!(2024 - 10 - 10),
+            ]
+        );
+    }
+
+    // Test to verify the observed holidays.
+    #[test]
+    fn test_observed_holidays() {
+        let calendar = TaiwanCalendar;
+
+        // Children's Day and Tomb Sweeping Day on the same Saturday, and on the same Sunday
+        assert!(calendar.is_holiday(date!(2020 - 04 - 02)));
+        assert!(calendar.is_holiday(date!(2020 - 04 - 03)));
+        assert!(calendar.is_holiday(date!(2021 - 04 - 02)));
+        assert!(calendar.is_holiday(date!(2021 - 04 - 05)));
+
+        // New Year's Day on a Saturday
+        assert!(calendar.is_holiday(date!(2021 - 12 - 31)));
+
+        // The holidays added in 2025
+        assert!(calendar.is_holiday(date!(2025 - 09 - 29)));
+        assert!(calendar.is_holiday(date!(2025 - 10 - 24)));
+        assert!(calendar.is_holiday(date!(2025 - 12 - 25)));
+        assert!(!calendar.is_holiday(date!(2024 - 12 - 25)));
+    }
+}
@@ -0,0 +1,296 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::calendar::Calendar;
+use crate::utilities::unpack_date;
+use time::macros::date;
+use time::{Date, Duration, Month};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Thailand calendar, following the holidays of the financial institutions
+/// announced by the Bank of Thailand.
+///
+/// The Buddhist holidays follow the Thai lunar calendar, and are listed
+/// for the years 2010 - 2029 only.
+pub struct ThailandCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Makha Bucha, Visakha Bucha and Asarnha Bucha: the full moon days of the
+/// 3rd, 6th and 8th months of the Thai lunar calendar (a month later in
+/// the years with an intercalary month).
+///
+/// The dates up to 2026 are the ones announced by the Bank of Thailand in its
+/// financial institutions holidays. The dates for 2027 - 2029 follow the year
+/// types of the Thai lunar calendar (an intercalary month in 2029, no intercalary
+/// day), and should be checked against the announcements when they are made.
+/// There is no entry after 2029: the table must be extended before then.
+#[rustfmt::skip]
+const BUDDHIST_HOLIDAYS: [(i32, Date, Date, Date); 20] = [
+    (2010, date!(2010 - 02 - 28), date!(2010 - 05 - 28), date!(2010 - 07 - 26)),
+    (2011, date!(2011 - 02 - 18), date!(2011 - 05 - 17), date!(2011 - 07 - 15)),
+    (2012, date!(2012 - 03 - 07), date!(2012 - 06 - 04), date!(2012 - 08 - 02)),
+    (2013, date!(2013 - 02 - 25), date!(2013 - 05 - 24), date!(2013 - 07 - 22)),
+    (2014, date!(2014 - 02 - 14), date!(2014 - 05 - 13), date!(2014 - 07 - 11)),
+    (2015, date!(2015 - 03 - 04), date!(2015 - 06 - 01), date!(2015 - 07 - 30)),
+    (2016, date!(2016 - 02 - 22), date!(2016 - 05 - 20), date!(2016 - 07 - 19)),
+    (2017, date!(2017 - 02 - 11), date!(2017 - 05 - 10), date!(2017 - 07 - 08)),
+    (2018, date!(2018 - 03 - 01), date!(2018 - 05 - 29), date!(2018 - 07 - 27)),
+    (2019, date!(2019 - 02 - 19), date!(2019 - 05 - 18), date!(2019 - 07 - 16)),
+    (2020, date!(2020 - 02 - 08), date!(2020 - 05 - 06), date!(2020 - 07 - 05)),
+    (2021, date!(2021 - 02 - 26), date!(2021 - 05 - 26), date!(2021 - 07 - 24)),
+    (2022, date!(2022 - 02 - 16), date!(2022 - 05 - 15), date!(2022 - 07 - 13)),
+    (2023, date!(2023 - 03 - 06), date!(2023 - 06 - 03), date!(2023 - 08 - 01)),
+    (2024, date!(2024 - 02 - 24), date!(2024 - 05 - 22), date!(2024 - 07 - 20)),
+    (2025, date!(2025 - 02 - 12), date!(2025 - 05 - 11), date!(2025 - 07 - 10)),
+    (2026, date!(2026 - 03 - 03), date!(2026 - 05 - 31), date!(2026 - 07 - 29)),
+    (2027, date!(2027 - 02 - 21), date!(2027 - 05 - 20), date!(2027 - 07 - 18)),
+    (2028, date!(2028 - 02 - 10), date!(2028 - 05 - 08), date!(2028 - 07 - 06)),
+    (2029, date!(2029 - 02 - 27), date!(2029 - 05 - 27), date!(2029 - 07 - 25)),
+];
+
+/// Royal ceremonies, and special holidays declared by the cabinet.
+#[rustfmt::skip]
+const SPECIAL_HOLIDAYS: [Date; 18] = [
+    date!(2017 - 10 - 26), // royal cremation of King Bhumibol
+    date!(2019 - 05 - 06), // coronation of King Vajiralongkorn
+    date!(2020 - 07 - 27), // Songkran, postponed
+    date!(2020 - 09 - 04), // Songkran, postponed
+    date!(2020 - 09 - 07), // Songkran, postponed
+    date!(2020 - 11 - 19), // Songkran, postponed
+    date!(2020 - 11 - 20), // Songkran, postponed
+    date!(2020 - 12 - 11), // Songkran, postponed
+    date!(2021 - 02 - 12), // special holiday
+    date!(2021 - 04 - 12), // special holiday
+    date!(2021 - 09 - 24), // special holiday
+    date!(2022 - 07 - 29), // special holiday
+    date!(2022 - 10 - 14), // special holiday
+    date!(2023 - 07 - 31), // special holiday
+    date!(2023 - 12 - 29), // special holiday
+    date!(2024 - 04 - 12), // special holiday
+    date!(2024 - 12 - 30), // special holiday
+    date!(2025 - 06 - 02), // special holiday
+];
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for ThailandCalendar {
+    fn name(&self) -> &'static str {
+        "Thailand"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.is_public_holiday(date)
+            || self.is_substitution_day(date)
+            || SPECIAL_HOLIDAYS.contains(&date)
+    }
+}
+
+impl ThailandCalendar {
+    fn is_public_holiday(&self, date: Date) -> bool {
+        let (y, m, d, _, _, _) = unpack_date(date, false);
+
+        // New Year's Day
+        (d == 1 && m == Month::January)
+            // Makha Bucha, Visakha Bucha and Asarnha Bucha
+            || self.is_buddhist_holiday(date)
+            // Chakri Memorial Day
+            || (d == 6 && m == Month::April)
+            // Songkran (postponed in 2020)
+            || ((13..=15).contains(&d) && m == Month::April && y != 2020)
+            // Labour Day
+            || (d == 1 && m == Month::May)
+            // Coronation Day (King Bhumibol until 2016, King Vajiralongkorn since 2020)
+            || (d == 5 && m == Month::May && y <= 2016)
+            || (d == 4 && m == Month::May && y >= 2020)
+            // Queen Suthida's Birthday (since 2019)
+            || (d == 3 && m == Month::June && y >= 2019)
+            // King Vajiralongkorn's Birthday (since 2017)
+            || (d == 28 && m == Month::July && y >= 2017)
+            // Queen Mother's Birthday
+            || (d == 12 && m == Month::August)
+            // King Bhumibol Memorial Day (since 2017)
+            || (d == 13 && m == Month::October && y >= 2017)
+            // Chulalongkorn Day
+            || (d == 23 && m == Month::October)
+            // King Bhumibol's Birthday
+            || (d == 5 && m == Month::December)
+            // Constitution Day
+            || (d == 10 && m == Month::December)
+            // New Year's Eve
+            || (d == 31 && m == Month::December)
+    }
+
+    fn is_buddhist_holiday(&self, date: Date) -> bool {
+        BUDDHIST_HOLIDAYS
+            .iter()
+            .filter(|(year, _, _, _)| *year == date.year())
+            .any(|&(_, makha, visakha, asarnha)| [makha, visakha, asarnha].contains(&date))
+    }
+
+    /// Function to check if the date is a Songkran day on a weekend.
+    fn is_songkran_weekend(&self, date: Date) -> bool {
+        let (y, m, d, _, _, _) = unpack_date(date, false);
+
+        (13..=15).contains(&d) && m == Month::April && y != 2020 && self.is_weekend(date)
+    }
+
+    /// Function to check if the date is a substitution day: the public holidays falling
+    /// on a weekend are observed on the next days which are neither a weekend nor a holiday
+    /// (a single day for the Songkran festival).
+    fn is_substitution_day(&self, date: Date) -> bool {
+        if self.is_weekend(date) || self.is_public_holiday(date) {
+            return false;
+        }
+
+        // Substitution days follow the weekend holidays within a few days
+        let mut pending = 0_usize;
+        let mut day = date - Duration::days(7);
+
+        while day < date {
+            match (self.is_weekend(day), self.is_public_holiday(day)) {
+                (true, true) if !self.is_songkran_weekend(day - Duration::DAY) => pending += 1,
+                (false, false) => pending = pending.saturating_sub(1),
+                _ => {}
+            }
+            day += Duration::DAY;
+        }
+
+        pending > 0
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_thailand {
+    use super::*;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = ThailandCalendar;
+        assert_eq!(calendar.name(), "Thailand");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = ThailandCalendar;
+        let sat = date!(2024 - 03 - 09);
+        let sun = date!(2024 - 03 - 10);
+        assert!(!calendar.is_business_day(sat));
+        assert!(!calendar.is_business_day(sun));
+    }
+
+    // Test to verify the full list of holidays of a year.
+    #[test]
+    fn test_holidays() {
+        let calendar = ThailandCalendar;
+
+        // New Year's Eve 2023, Makha Bucha and Songkran on a weekend
+        assert_eq!(
+            calendar.all_holidays_between(date!(2024 - 01 - 01), date!(2024 - 12 - 31)),
+            vec![
+                date!(2024 - 01 - 01),
+                date!(2024 - 01 - 02),
+                date!(2024 - 02 - 24),
+                date!(2024 - 02 - 26),
+                date!(2024 - 04 - 06),
+                date!(2024 - 04 - 08),
+                date!(2024 - 04 - 12),
+                date!(2024 - 04 - 13),
+                date!(2024 - 04 - 14),
+                date!(2024 - 04 - 15),
+                date!(2024 - 04 - 16),
+                date!(2024 - 05 - 01),
+                date!(2024 - 05 - 04),
+                date!(2024 - 05 - 06),
+                date!(2024 - 05 - 22),
+                date!(2024 - 06 - 03),
+                date!(2024 - 07 - 20),
+                date!(2024 - 07 - 22),
+                date!(2024 - 07 - 28),
+                date!(2024 - 07 - 29),
+                date!(2024 - 08 - 12),
+                date!(2024 - 10 - 13),
+                date!(2024 - 10 - 14),
+                date!(2024 - 10 - 23),
+                date!(2024 - 12 - 05),
+                date!(2024 - 12 - 10),
+                date!(2024 - 12 - 30),
+                date!(2024 - 12 - 31),
+            ]
+        );
+    }
+
+    // Test to verify the royal observances, and the Songkran holidays postponed in 2020.
+    #[test]
+    fn test_royal_and_special_holidays() {
+        let calendar = ThailandCalendar;
+
+        assert!(calendar.is_holiday(date!(2016 - 05 - 05)));
+        assert!(!calendar.is_holiday(date!(2018 - 05 - 04)));
+        assert!(calendar.is_holiday(date!(2019 - 05 - 06)));
+        assert!(calendar.is_holiday(date!(2017 - 10 - 26)));
+
+        assert!(!calendar.is_holiday(date!(2020 - 04 - 13)));
+        assert!(calendar.is_holiday(date!(2020 - 07 - 27)));
+
+        assert!(calendar.is_holiday(date!(2025 - 06 - 02)));
+    }
+
+    // Test to verify the structure of the Buddhist holidays table: consecutive years,
+    // and the holidays two and three lunar months after Makha Bucha.
+    #[test]
+    fn test_buddhist_holidays_table() {
+        for window in BUDDHIST_HOLIDAYS.windows(2) {
+            assert_eq!(window[1].0, window[0].0 + 1);
+        }
+
+        for (year, makha, visakha, asarnha) in BUDDHIST_HOLIDAYS {
+            assert!([makha, visakha, asarnha]
+                .iter()
+                .all(|date| date.year() == year));
+            assert!([88, 89].contains(&(visakha - makha).whole_days()));
+            assert!([59, 60].contains(&(asarnha - visakha).whole_days()));
+        }
+
+        assert!(ThailandCalendar.is_holiday(date!(2027 - 02 - 22)));
+        assert!(ThailandCalendar.is_holiday(date!(2029 - 07 - 25)));
+    }
+
+    // Test to verify the Buddhist holidays are known for the next year,
+    // so that the table is extended before it runs out.
+    #[test]
+    fn test_buddhist_holidays_coverage() {
+        let next_year = time::OffsetDateTime::now_utc().year() + 1;
+        let last_year = BUDDHIST_HOLIDAYS.last().map(|(year, _, _, _)| *year);
+
+        assert!(
+            last_year >= Some(next_year),
+            "BUDDHIST_HOLIDAYS must be extended to {next_year}"
+        );
+    }
+}
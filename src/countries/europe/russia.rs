@@ -0,0 +1,295 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::calendar::Calendar;
+use crate::utilities::unpack_date;
+use time::macros::date;
+use time::{Date, Duration, Month};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Russia settlement calendar, followed by the Moscow Exchange (MOEX).
+///
+/// The non-working holidays of the Labour Code, the days off moved when a holiday
+/// falls on a weekend, and the days off transferred by the yearly government decree,
+/// with the weekend days they are transferred from becoming working days.
+pub struct RussiaCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Days off transferred by government decree, by year: (from, to).
+/// The day `from` is a working day, unless it is a holiday falling on a weekend.
+#[rustfmt::skip]
+const TRANSFERS: [&[(Date, Date)]; 27] = [
+    // 2000
+    &[(date!(2000 - 05 - 06), date!(2000 - 05 - 08)), (date!(2000 - 11 - 04), date!(2000 - 11 - 06)), (date!(2000 - 12 - 09), date!(2000 - 12 - 11))],
+    // 2001
+    &[(date!(2001 - 03 - 11), date!(2001 - 03 - 09)), (date!(2001 - 04 - 28), date!(2001 - 04 - 30)), (date!(2001 - 06 - 09), date!(2001 - 06 - 11)), (date!(2001 - 12 - 29), date!(2001 - 12 - 31))],
+    // 2002
+    &[(date!(2002 - 04 - 27), date!(2002 - 05 - 03)), (date!(2002 - 05 - 18), date!(2002 - 05 - 10)), (date!(2002 - 11 - 10), date!(2002 - 11 - 08)), (date!(2002 - 12 - 14), date!(2002 - 12 - 13))],
+    // 2003
+    &[(date!(2003 - 01 - 04), date!(2003 - 01 - 03)), (date!(2003 - 01 - 05), date!(2003 - 01 - 06)), (date!(2003 - 06 - 21), date!(2003 - 06 - 13))],
+    // 2004
+    &[],
+    // 2005
+    &[(date!(2005 - 03 - 05), date!(2005 - 03 - 07)), (date!(2005 - 05 - 14), date!(2005 - 05 - 10))],
+    // 2006
+    &[(date!(2006 - 02 - 26), date!(2006 - 02 - 24)), (date!(2006 - 05 - 13), date!(2006 - 05 - 08))],
+    // 2007
+    &[(date!(2007 - 04 - 28), date!(2007 - 04 - 30)), (date!(2007 - 06 - 09), date!(2007 - 06 - 11)), (date!(2007 - 12 - 29), date!(2007 - 12 - 31))],
+    // 2008
+    &[(date!(2008 - 05 - 04), date!(2008 - 05 - 02)), (date!(2008 - 06 - 07), date!(2008 - 06 - 13)), (date!(2008 - 11 - 01), date!(2008 - 11 - 03))],
+    // 2009
+    &[(date!(2009 - 01 - 11), date!(2009 - 01 - 09))],
+    // 2010
+    &[(date!(2010 - 02 - 27), date!(2010 - 02 - 22)), (date!(2010 - 11 - 13), date!(2010 - 11 - 05))],
+    // 2011
+    &[(date!(2011 - 03 - 05), date!(2011 - 03 - 07))],
+    // 2012
+    &[(date!(2012 - 03 - 11), date!(2012 - 03 - 09)), (date!(2012 - 04 - 28), date!(2012 - 04 - 30)), (date!(2012 - 05 - 05), date!(2012 - 05 - 07)), (date!(2012 - 05 - 12), date!(2012 - 05 - 08)), (date!(2012 - 06 - 09), date!(2012 - 06 - 11)), (date!(2012 - 12 - 29), date!(2012 - 12 - 31))],
+    // 2013
+    &[(date!(2013 - 01 - 05), date!(2013 - 05 - 02)), (date!(2013 - 01 - 06), date!(2013 - 05 - 03)), (date!(2013 - 02 - 23), date!(2013 - 05 - 10))],
+    // 2014
+    &[(date!(2014 - 01 - 04), date!(2014 - 05 - 02)), (date!(2014 - 01 - 05), date!(2014 - 06 - 13)), (date!(2014 - 11 - 01), date!(2014 - 11 - 03))],
+    // 2015
+    &[(date!(2015 - 01 - 03), date!(2015 - 01 - 09)), (date!(2015 - 01 - 04), date!(2015 - 05 - 04))],
+    // 2016
+    &[(date!(2016 - 01 - 02), date!(2016 - 05 - 03)), (date!(2016 - 01 - 03), date!(2016 - 03 - 07)), (date!(2016 - 02 - 20), date!(2016 - 02 - 22))],
+    // 2017
+    &[(date!(2017 - 01 - 01), date!(2017 - 02 - 24)), (date!(2017 - 01 - 07), date!(2017 - 05 - 08))],
+    // 2018
+    &[(date!(2018 - 01 - 06), date!(2018 - 03 - 09)), (date!(2018 - 01 - 07), date!(2018 - 05 - 02)), (date!(2018 - 04 - 28), date!(2018 - 04 - 30)), (date!(2018 - 06 - 09), date!(2018 - 06 - 11)), (date!(2018 - 12 - 29), date!(2018 - 12 - 31))],
+    // 2019
+    &[(date!(2019 - 01 - 05), date!(2019 - 05 - 02)), (date!(2019 - 01 - 06), date!(2019 - 05 - 03)), (date!(2019 - 02 - 23), date!(2019 - 05 - 10))],
+    // 2020
+    &[(date!(2020 - 01 - 04), date!(2020 - 05 - 04)), (date!(2020 - 01 - 05), date!(2020 - 05 - 05))],
+    // 2021
+    &[(date!(2021 - 01 - 02), date!(2021 - 11 - 05)), (date!(2021 - 01 - 03), date!(2021 - 12 - 31)), (date!(2021 - 02 - 20), date!(2021 - 02 - 22))],
+    // 2022
+    &[(date!(2022 - 01 - 01), date!(2022 - 05 - 03)), (date!(2022 - 01 - 02), date!(2022 - 05 - 10)), (date!(2022 - 03 - 05), date!(2022 - 03 - 07))],
+    // 2023
+    &[(date!(2023 - 01 - 01), date!(2023 - 02 - 24)), (date!(2023 - 01 - 08), date!(2023 - 05 - 08))],
+    // 2024
+    &[(date!(2024 - 01 - 06), date!(2024 - 05 - 10)), (date!(2024 - 01 - 07), date!(2024 - 12 - 31)), (date!(2024 - 04 - 27), date!(2024 - 04 - 29)), (date!(2024 - 11 - 02), date!(2024 - 04 - 30)), (date!(2024 - 12 - 28), date!(2024 - 12 - 30))],
+    // 2025
+    &[(date!(2025 - 01 - 04), date!(2025 - 05 - 02)), (date!(2025 - 01 - 05), date!(2025 - 12 - 31)), (date!(2025 - 03 - 08), date!(2025 - 06 - 13)), (date!(2025 - 11 - 01), date!(2025 - 11 - 03))],
+    // 2026
+    &[(date!(2026 - 01 - 03), date!(2026 - 01 - 09)), (date!(2026 - 01 - 04), date!(2026 - 12 - 31))],
+];
+
+/// First year of the transfer tables.
+const TRANSFERS_FIRST_YEAR: i32 = 2000;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for RussiaCalendar {
+    fn name(&self) -> &'static str {
+        "Russia"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.is_public_holiday(date)
+            || self.is_moved_day_off(date)
+            || self.transfers(date).any(|(_, to)| to == date)
+    }
+
+    fn is_working_weekend(&self, date: Date) -> bool {
+        self.is_weekend(date)
+            && !self.is_public_holiday(date)
+            && self.transfers(date).any(|(from, _)| from == date)
+    }
+}
+
+impl RussiaCalendar {
+    /// Function to check if the date is a non-working holiday, under the Labour Code
+    /// (since 2002, and the Labour Code of the RSFSR before) and its amendments.
+    fn is_public_holiday(&self, date: Date) -> bool {
+        let (y, m, d, _, _, _) = unpack_date(date, false);
+
+        // New Year holidays (1 - 2 January until 2004, 1 - 5 January until 2012, 1 - 6 and 8 January since)
+        ((d <= 2 || d <= 5 && y >= 2005 || (d <= 8 && d != 7 && y >= 2013)) && m == Month::January)
+            // Orthodox Christmas
+            || (d == 7 && m == Month::January)
+            // Defender of the Fatherland Day (since 2002)
+            || (d == 23 && m == Month::February && y >= 2002)
+            // International Women's Day
+            || (d == 8 && m == Month::March)
+            // Spring and Labour Day (and 2 May until 2004)
+            || (d == 1 && m == Month::May)
+            || (d == 2 && m == Month::May && y <= 2004)
+            // Victory Day
+            || (d == 9 && m == Month::May)
+            // Russia Day
+            || (d == 12 && m == Month::June)
+            // Unity Day (since 2005)
+            || (d == 4 && m == Month::November && y >= 2005)
+            // Day of Accord and Reconciliation (until 2004)
+            || (d == 7 && m == Month::November && y <= 2004)
+            // Constitution Day (until 2004)
+            || (d == 12 && m == Month::December && y <= 2004)
+    }
+
+    /// Function to list the transfers of the decrees of the year of the date, and of the years around it.
+    fn transfers(&self, date: Date) -> impl Iterator<Item = (Date, Date)> {
+        (date.year() - 1..=date.year() + 1)
+            .filter_map(|year| usize::try_from(year - TRANSFERS_FIRST_YEAR).ok())
+            .filter_map(|index| TRANSFERS.get(index))
+            .flat_map(|transfers| transfers.iter().copied())
+    }
+
+    /// Function to check if the day off of a holiday falling on a weekend is moved:
+    /// to the next working day, unless the decree transfers it elsewhere
+    /// (always the case for the January holidays, since 2013).
+    fn is_moved_holiday(&self, date: Date) -> bool {
+        let (y, m, d, _, _, _) = unpack_date(date, false);
+
+        self.is_weekend(date)
+            && self.is_public_holiday(date)
+            && !(y >= 2013 && d <= 8 && m == Month::January)
+            && !self.transfers(date).any(|(from, _)| from == date)
+    }
+
+    /// Function to check if the date is a working day to which the day off
+    /// of a holiday falling on a weekend is moved.
+    fn is_moved_day_off(&self, date: Date) -> bool {
+        let is_day_off = |day: Date| {
+            (self.is_weekend(day) && !self.is_working_weekend(day))
+                || self.is_public_holiday(day)
+                || self.transfers(day).any(|(_, to)| to == day)
+        };
+
+        if is_day_off(date) {
+            return false;
+        }
+
+        // The days off are moved to the next working days after the holidays
+        let mut pending = 0_usize;
+        let mut day = date - Duration::days(14);
+
+        while day < date {
+            if self.is_moved_holiday(day) {
+                pending += 1;
+            } else if !is_day_off(day) {
+                pending = pending.saturating_sub(1);
+            }
+            day += Duration::DAY;
+        }
+
+        pending > 0
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_russia {
+    use super::*;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = RussiaCalendar;
+        assert_eq!(calendar.name(), "Russia");
+    }
+
+    // Test to verify if weekends are not considered business days, except the working weekends.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = RussiaCalendar;
+
+        assert!(!calendar.is_business_day(date!(2024 - 03 - 09)));
+        assert!(!calendar.is_business_day(date!(2024 - 03 - 10)));
+
+        assert!(calendar.is_working_weekend(date!(2024 - 04 - 27)));
+        assert!(calendar.is_business_day(date!(2024 - 11 - 02)));
+        assert!(calendar.is_business_day(date!(2012 - 03 - 11)));
+        assert!(calendar.is_business_day(date!(2009 - 01 - 11)));
+
+        // A holiday on a weekend transferred by decree stays a day off
+        assert!(!calendar.is_working_weekend(date!(2024 - 01 - 06)));
+        assert!(!calendar.is_business_day(date!(2019 - 02 - 23)));
+    }
+
+    // Test to verify the days off of a full year.
+    #[test]
+    fn test_holidays() {
+        let calendar = RussiaCalendar;
+
+        let days_off: Vec<Date> = calendar
+            .all_holidays_between(date!(2024 - 01 - 01), date!(2024 - 12 - 31))
+            .into_iter()
+            .filter(|date| !calendar.is_weekend(*date))
+            .collect();
+
+        assert_eq!(
+            days_off,
+            vec![
+                date!(2024 - 01 - 01),
+                date!(2024 - 01 - 02),
+                date!(2024 - 01 - 03),
+                date!(2024 - 01 - 04),
+                date!(2024 - 01 - 05),
+                date!(2024 - 01 - 08),
+                date!(2024 - 02 - 23),
+                date!(2024 - 03 - 08),
+                date!(2024 - 04 - 29),
+                date!(2024 - 04 - 30),
+                date!(2024 - 05 - 01),
+                date!(2024 - 05 - 09),
+                date!(2024 - 05 - 10),
+                date!(2024 - 06 - 12),
+                date!(2024 - 11 - 04),
+                date!(2024 - 12 - 30),
+                date!(2024 - 12 - 31),
+            ]
+        );
+    }
+
+    // Test to verify the days off moved from holidays on a weekend.
+    #[test]
+    fn test_moved_days_off() {
+        let calendar = RussiaCalendar;
+
+        // Unity Day and Russia Day on a weekend
+        assert!(calendar.is_holiday(date!(2023 - 11 - 06)));
+        assert!(calendar.is_holiday(date!(2022 - 06 - 13)));
+
+        // The January holidays on a weekend, until 2012
+        assert!(calendar.is_holiday(date!(2011 - 01 - 06)));
+        assert!(calendar.is_holiday(date!(2011 - 01 - 10)));
+        assert!(calendar.is_holiday(date!(2012 - 01 - 09)));
+        assert!(calendar.is_business_day(date!(2012 - 01 - 10)));
+
+        // and transferred by decree since 2013
+        assert!(calendar.is_business_day(date!(2023 - 01 - 09)));
+        assert!(calendar.is_holiday(date!(2023 - 02 - 24)));
+
+        // A holiday on a weekend transferred elsewhere by decree
+        assert!(calendar.is_business_day(date!(2019 - 02 - 25)));
+        assert!(calendar.is_holiday(date!(2019 - 05 - 10)));
+
+        // The holidays before 2005
+        assert!(calendar.is_holiday(date!(2004 - 12 - 13)));
+        assert!(calendar.is_business_day(date!(2005 - 12 - 12)));
+    }
+}
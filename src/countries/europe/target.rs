@@ -0,0 +1,153 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPORTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::calendar::Calendar;
+use crate::utilities::unpack_date;
+use time::{Date, Month};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// TARGET (Trans-European Automated Real-time Gross settlement Express Transfer)
+/// calendar, used for euro settlement (EUR swaps, €STR, EURIBOR).
+///
+/// The closing days were set by the ECB when TARGET started in 1999,
+/// and extended in 2000. TARGET2 (2007) and T2 (2023) kept the same days.
+#[derive(Default)]
+pub struct TargetCalendar;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS, METHODS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Calendar for TargetCalendar {
+    fn name(&self) -> &'static str {
+        "TARGET"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let (y, m, d, _wd, yd, em) = unpack_date(date, false);
+
+        if (
+            // New Year's Day
+            (d == 1 && m == Month::January)
+
+            // Good Friday (since 2000)
+            || (yd == em - 3 && y >= 2000)
+
+            // Easter Monday (since 2000)
+            || (yd == em && y >= 2000)
+
+            // Labour Day (since 2000)
+            || (d == 1 && m == Month::May && y >= 2000)
+
+            // Christmas
+            || (d == 25 && m == Month::December)
+
+            // Day of Goodwill (since 2000)
+            || (d == 26 && m == Month::December && y >= 2000)
+
+            // December 31st, 1998, 1999, and 2001 only
+            || (d == 31 && m == Month::December && (y == 1998 || y == 1999 || y == 2001))
+        ) {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl TargetCalendar {
+    /// Create a new instance of the TARGET calendar.
+    pub fn new() -> Self {
+        TargetCalendar
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_target {
+    use super::*;
+    use time::macros::date;
+
+    // Test to verify the name() method.
+    #[test]
+    fn test_name() {
+        let calendar = TargetCalendar;
+        assert_eq!(calendar.name(), "TARGET");
+    }
+
+    // Test to verify if weekends are not considered business days.
+    #[test]
+    fn test_is_weekend() {
+        let calendar = TargetCalendar;
+        let sat = date!(2023 - 08 - 26);
+        let sun = date!(2023 - 08 - 27);
+        assert!(!calendar.is_business_day(sat));
+        assert!(!calendar.is_business_day(sun));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for public holidays.
+    #[test]
+    fn test_is_public_holiday() {
+        let calendar = TargetCalendar;
+        let new_years_day = date!(2024 - 01 - 01);
+        let good_friday = date!(2024 - 03 - 29);
+        let easter_monday = date!(2024 - 04 - 01);
+        let labour_day = date!(2024 - 05 - 01);
+        let christmas = date!(2024 - 12 - 25);
+        let day_of_goodwill = date!(2024 - 12 - 26);
+
+        assert!(!calendar.is_business_day(new_years_day));
+        assert!(!calendar.is_business_day(good_friday));
+        assert!(!calendar.is_business_day(easter_monday));
+        assert!(!calendar.is_business_day(labour_day));
+        assert!(!calendar.is_business_day(christmas));
+        assert!(!calendar.is_business_day(day_of_goodwill));
+    }
+
+    // Test to verify the rule changes by year.
+    #[test]
+    fn test_historical_closures() {
+        let calendar = TargetCalendar;
+
+        // Good Friday, Easter Monday and the Day of Goodwill were not closing days in 1999
+        assert!(calendar.is_business_day(date!(1999 - 04 - 02)));
+        assert!(calendar.is_business_day(date!(1999 - 04 - 05)));
+        assert!(!calendar.is_holiday(date!(1999 - 12 - 26)));
+
+        // December 31st closures
+        assert!(!calendar.is_business_day(date!(1998 - 12 - 31)));
+        assert!(!calendar.is_business_day(date!(1999 - 12 - 31)));
+        assert!(calendar.is_business_day(date!(2000 - 12 - 29)));
+        assert!(!calendar.is_business_day(date!(2001 - 12 - 31)));
+        assert!(calendar.is_business_day(date!(2002 - 12 - 31)));
+    }
+
+    // Test to verify if the is_business_day() method properly accounts for regular business days.
+    #[test]
+    fn test_is_regular_business_day() {
+        let calendar = TargetCalendar;
+
+        // National holidays are TARGET business days
+        assert!(calendar.is_business_day(date!(2023 - 10 - 03)));
+        assert!(calendar.is_business_day(date!(2023 - 05 - 18)));
+        assert!(calendar.is_business_day(date!(2023 - 12 - 27)));
+    }
+}
@@ -13,9 +13,10 @@
 // IMPORTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::calendar::Calendar;
+use crate::calendar::{Calendar, EarlyClose};
 use crate::utilities::unpack_date;
-use time::{Date, Month, Weekday};
+use time::macros::time;
+use time::{Date, Month, Time, Weekday};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, TRAITS
@@ -24,6 +25,30 @@ use time::{Date, Month, Weekday};
 /// UnitedKingdom national holiday calendar.
 pub struct UnitedKingdomCalendar;
 
+/// London exchange calendar, shared by the London Stock Exchange (LSE)
+/// and the London Metal Exchange (LME).
+///
+/// Closed on the England and Wales bank holidays, with half-day sessions
+/// on Christmas Eve and New Year's Eve, or on the Friday before when they
+/// fall on a weekend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LondonExchangeCalendar {
+    exchange: LondonExchange,
+}
+
+/// London exchanges following the `LondonExchangeCalendar`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LondonExchange {
+    /// London Stock Exchange (LSE).
+    StockExchange,
+
+    /// London Metal Exchange (LME).
+    MetalExchange,
+}
+
+/// Close time of the half-day sessions of the London exchanges.
+const HALF_DAY_CLOSE: Time = time!(12:30);
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS, METHODS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -83,6 +108,64 @@ impl UnitedKingdomCalendar {
     }
 }
 
+impl Calendar for LondonExchangeCalendar {
+    fn name(&self) -> &'static str {
+        match self.exchange {
+            LondonExchange::StockExchange => "London Stock Exchange",
+            LondonExchange::MetalExchange => "London Metal Exchange",
+        }
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        UnitedKingdomCalendar.is_holiday(date)
+    }
+}
+
+impl EarlyClose for LondonExchangeCalendar {
+    fn early_close(&self, date: Date) -> Option<Time> {
+        if self.is_half_day(date) {
+            return Some(HALF_DAY_CLOSE);
+        }
+
+        None
+    }
+}
+
+impl LondonExchangeCalendar {
+    /// Create a new instance of the calendar of a London exchange.
+    pub fn new(exchange: LondonExchange) -> Self {
+        Self { exchange }
+    }
+
+    /// Create a new instance of the London Stock Exchange calendar.
+    pub fn lse() -> Self {
+        Self::new(LondonExchange::StockExchange)
+    }
+
+    /// Create a new instance of the London Metal Exchange calendar.
+    pub fn lme() -> Self {
+        Self::new(LondonExchange::MetalExchange)
+    }
+
+    /// Get the exchange of the calendar.
+    pub fn exchange(&self) -> LondonExchange {
+        self.exchange
+    }
+
+    /// Check if the date is a half-day session: the last business day
+    /// on or before Christmas Eve, and on or before New Year's Eve.
+    pub fn is_half_day(&self, date: Date) -> bool {
+        let (_, m, d, wd, _, _) = unpack_date(date, false);
+
+        self.is_business_day(date)
+            && m == Month::December
+            && (d == 24
+                || d == 31
+                // Friday before Christmas Eve or New Year's Eve on a weekend
+                || (wd == Weekday::Friday && matches!(d, 22 | 23 | 29 | 30)))
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // UNIT TESTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -140,4 +223,48 @@ mod test_united_kingdom {
         assert!(calendar.is_business_day(regular_day2));
         assert!(calendar.is_business_day(regular_day3));
     }
+
+    // Test to verify the London exchange calendars.
+    #[test]
+    fn test_exchanges() {
+        let lse = LondonExchangeCalendar::lse();
+        let lme = LondonExchangeCalendar::lme();
+        assert_eq!(lse.name(), "London Stock Exchange");
+        assert_eq!(lme.name(), "London Metal Exchange");
+        assert_eq!(lme.exchange(), LondonExchange::MetalExchange);
+
+        for date in [
+            date!(2023 - 05 - 08),
+            date!(2022 - 09 - 19),
+            date!(2024 - 08 - 26),
+        ] {
+            assert!(!lse.is_business_day(date));
+            assert!(!lme.is_business_day(date));
+        }
+    }
+
+    // Test to verify the half-day sessions of the London exchanges.
+    #[test]
+    fn test_half_days() {
+        let lse = LondonExchangeCalendar::lse();
+        let lme = LondonExchangeCalendar::lme();
+
+        assert_eq!(lse.early_close(date!(2024 - 12 - 24)), Some(time!(12:30)));
+        assert_eq!(lse.early_close(date!(2024 - 12 - 31)), Some(time!(12:30)));
+        assert_eq!(lme.early_close(date!(2024 - 12 - 24)), Some(time!(12:30)));
+        assert_eq!(lse.early_close(date!(2024 - 12 - 23)), None);
+        assert_eq!(lse.early_close(date!(2024 - 12 - 20)), None);
+
+        // Christmas Eve and New Year's Eve on a weekend: the Friday before
+        assert!(!lse.is_early_close(date!(2023 - 12 - 24)));
+        assert_eq!(
+            lse.all_early_closes_between(date!(2023 - 01 - 01), date!(2023 - 12 - 31)),
+            vec![
+                (date!(2023 - 12 - 22), time!(12:30)),
+                (date!(2023 - 12 - 29), time!(12:30)),
+            ]
+        );
+        assert!(lse.is_half_day(date!(2022 - 12 - 23)));
+        assert!(lse.is_half_day(date!(2022 - 12 - 30)));
+    }
 }
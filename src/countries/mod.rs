@@ -27,6 +27,18 @@ pub mod asia {
     pub mod india;
     /// This module defines Indonesia holidays and calendars.
     pub mod indonesia;
+    /// This module defines Israel holidays and calendars.
+    pub mod israel;
+    /// This module defines Japan holidays and calendars.
+    pub mod japan;
+    /// This module defines Singapore holidays and calendars.
+    pub mod singapore;
+    /// This module defines South Korea holidays and calendars.
+    pub mod south_korea;
+    /// This module defines Taiwan holidays and calendars.
+    pub mod taiwan;
+    /// This module defines Thailand holidays and calendars.
+    pub mod thailand;
 }
 
 /// Calendars implemented for European countries.
@@ -47,6 +59,10 @@ pub mod europe {
     pub mod hungary;
     /// This module defines Iceland holidays and calendars.
     pub mod iceland;
+    /// This module defines Russia holidays and calendars.
+    pub mod russia;
+    /// This module defines the TARGET (euro settlement) calendar.
+    pub mod target;
     /// This module defines United Kingdom holidays and calendars.
     pub mod united_kingdom;
 }
@@ -13,9 +13,10 @@
 // IMPORTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::calendar::Calendar;
-use crate::utilities::unpack_date;
-use time::{Date, Month, Weekday};
+use crate::calendar::{Calendar, EarlyClose};
+use crate::utilities::{is_weekend, unpack_date};
+use time::macros::{date, time};
+use time::{Date, Month, Time, Weekday};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, TRAITS
@@ -24,6 +25,87 @@ use time::{Date, Month, Weekday};
 /// United States of America national holiday calendar.
 pub struct UnitedStatesCalendar;
 
+/// New York Stock Exchange (NYSE) calendar.
+///
+/// Includes Good Friday, the historical special closures of the exchange,
+/// and the 1:00 p.m. early closes.
+#[derive(Default)]
+pub struct NyseCalendar;
+
+/// US Government Bond (SIFMA) settlement calendar.
+///
+/// Follows the SIFMA recommended holiday schedule for US government securities,
+/// which closes on Columbus Day and Veterans Day, and on Good Friday in most years.
+/// Early closes (2:00 p.m.) are recommended on the business day before most holidays.
+#[derive(Default)]
+pub struct UnitedStatesGovernmentBondCalendar;
+
+/// Federal Reserve Bank holiday calendar (Fedwire and the Federal Reserve Banks).
+///
+/// Uses the US federal holidays, but a holiday on a Sunday is observed on the
+/// following Monday, while a holiday on a Saturday is not observed at all.
+#[derive(Default)]
+pub struct FederalReserveCalendar;
+
+/// Secured Overnight Financing Rate (SOFR) fixing calendar.
+///
+/// SOFR is published on US Government Bond (SIFMA) business days,
+/// except Good Friday, which is never a fixing day.
+#[derive(Default)]
+pub struct SofrCalendar;
+
+/// Unscheduled NYSE closures (weather, national days of mourning, etc.).
+#[rustfmt::skip]
+const NYSE_SPECIAL_CLOSURES: [Date; 27] = [
+    date!(1954 - 12 - 24), // Christmas Eve
+    date!(1956 - 12 - 24), // Christmas Eve
+    date!(1958 - 12 - 26), // Day after Christmas
+    date!(1961 - 05 - 29), // Day before Decoration Day
+    date!(1963 - 11 - 25), // President Kennedy's funeral
+    date!(1965 - 12 - 24), // Christmas Eve
+    date!(1968 - 02 - 12), // Lincoln's birthday
+    date!(1968 - 04 - 09), // Day of mourning for Martin Luther King
+    date!(1968 - 07 - 05), // Day after Independence Day
+    date!(1969 - 02 - 10), // Snow
+    date!(1969 - 03 - 31), // President Eisenhower's funeral
+    date!(1969 - 07 - 21), // Lunar exploration (Apollo 11)
+    date!(1972 - 12 - 28), // President Truman's funeral
+    date!(1973 - 01 - 25), // President Johnson's funeral
+    date!(1977 - 07 - 14), // Blackout in New York City
+    date!(1985 - 09 - 27), // Hurricane Gloria
+    date!(1994 - 04 - 27), // President Nixon's funeral
+    date!(2001 - 09 - 11), // September 11 attacks
+    date!(2001 - 09 - 12), // September 11 attacks
+    date!(2001 - 09 - 13), // September 11 attacks
+    date!(2001 - 09 - 14), // September 11 attacks
+    date!(2004 - 06 - 11), // President Reagan's funeral
+    date!(2007 - 01 - 02), // President Ford's funeral
+    date!(2012 - 10 - 29), // Hurricane Sandy
+    date!(2012 - 10 - 30), // Hurricane Sandy
+    date!(2018 - 12 - 05), // President G. H. W. Bush's funeral
+    date!(2025 - 01 - 09), // President Carter's funeral
+];
+
+/// NYSE early close time.
+const NYSE_EARLY_CLOSE: Time = time!(13:00);
+
+/// Unscheduled SIFMA full closures.
+#[rustfmt::skip]
+const SIFMA_SPECIAL_CLOSURES: [Date; 5] = [
+    date!(2001 - 09 - 11), // September 11 attacks
+    date!(2001 - 09 - 12), // September 11 attacks
+    date!(2004 - 06 - 11), // President Reagan's funeral
+    date!(2012 - 10 - 30), // Hurricane Sandy
+    date!(2018 - 12 - 05), // President G. H. W. Bush's funeral
+];
+
+/// Years in which SIFMA recommended an early close on Good Friday instead of
+/// a full close, because the employment report was released on that day.
+const SIFMA_GOOD_FRIDAY_EARLY_CLOSE_YEARS: [i32; 6] = [2007, 2010, 2012, 2015, 2021, 2023];
+
+/// SIFMA recommended early close time.
+const SIFMA_EARLY_CLOSE: Time = time!(14:00);
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS, METHODS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -157,6 +239,305 @@ impl UnitedStatesCalendar {
     }
 }
 
+impl Calendar for NyseCalendar {
+    fn name(&self) -> &'static str {
+        "New York Stock Exchange"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let (y, m, d, wd, yd, em) = unpack_date(date, false);
+        let us = UnitedStatesCalendar;
+
+        if (
+            // New Year's Day (possibly moved to Monday if on Sunday)
+            ((d == 1 || (d == 2 && wd == Weekday::Monday)) && m == Month::January)
+
+            // Martin Luther King's birthday (third Monday in January)
+            || ((15..=21).contains(&d) && wd == Weekday::Monday && m == Month::January && y >= 1998)
+
+            // Washington's birthday (third Monday in February)
+            || us.is_washington_birthday(date)
+
+            // Good Friday (the exchange was open in 1906 and 1907)
+            || (yd == em - 3 && y != 1906 && y != 1907)
+
+            // Memorial Day (last Monday in May)
+            || us.is_memorial_day(date)
+
+            // Juneteenth (Monday if Sunday or Friday if Saturday, since 2022)
+            || us.is_juneteenth(date, true)
+
+            // Independence Day (Monday if Sunday or Friday if Saturday)
+            || ((d == 4 || (d == 5 && wd == Weekday::Monday) || (d == 3 && wd == Weekday::Friday)) && m == Month::July)
+
+            // Labor Day (first Monday in September)
+            || us.is_labor_day(date)
+
+            // Thanksgiving Day (fourth Thursday in November)
+            || ((22..=28).contains(&d) && wd == Weekday::Thursday && m == Month::November)
+
+            // Christmas (Monday if Sunday or Friday if Saturday)
+            || ((d == 25 || (d == 26 && wd == Weekday::Monday) || (d == 24 && wd == Weekday::Friday)) && m == Month::December)
+
+            // Presidential election days (every year until 1968, then every four years until 1980)
+            || ((y <= 1968 || (y <= 1980 && y % 4 == 0)) && m == Month::November && (2..=8).contains(&d) && wd == Weekday::Tuesday)
+
+            // Special closures
+            || NYSE_SPECIAL_CLOSURES.contains(&date)
+        ) {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl EarlyClose for NyseCalendar {
+    fn early_close(&self, date: Date) -> Option<Time> {
+        let (y, m, d, wd, _, _) = unpack_date(date, false);
+
+        // holidays, weekends, and days before the current early close rules
+        if !self.is_business_day(date) || y < 1993 {
+            return None;
+        }
+
+        if (
+            // Day before Independence Day
+            (d == 3 && m == Month::July)
+
+            // Day after Thanksgiving Day
+            || ((23..=29).contains(&d) && wd == Weekday::Friday && m == Month::November)
+
+            // Christmas Eve
+            || (d == 24 && m == Month::December)
+        ) {
+            return Some(NYSE_EARLY_CLOSE);
+        }
+
+        None
+    }
+}
+
+impl NyseCalendar {
+    /// Create a new instance of the New York Stock Exchange calendar.
+    pub fn new() -> Self {
+        NyseCalendar
+    }
+}
+
+impl Calendar for UnitedStatesGovernmentBondCalendar {
+    fn name(&self) -> &'static str {
+        "United States Government Bond (SIFMA)"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let (y, m, d, wd, yd, em) = unpack_date(date, false);
+        let us = UnitedStatesCalendar;
+
+        if (
+            // New Year's Day (possibly moved to Monday if on Sunday)
+            ((d == 1 || (d == 2 && wd == Weekday::Monday)) && m == Month::January)
+
+            // Martin Luther King's birthday (third Monday in January)
+            || ((15..=21).contains(&d) && wd == Weekday::Monday && m == Month::January && y >= 1983)
+
+            // Washington's birthday (third Monday in February)
+            || us.is_washington_birthday(date)
+
+            // Good Friday (an early close in some years)
+            || (yd == em - 3 && !SIFMA_GOOD_FRIDAY_EARLY_CLOSE_YEARS.contains(&y))
+
+            // Memorial Day (last Monday in May)
+            || us.is_memorial_day(date)
+
+            // Juneteenth (Monday if Sunday or Friday if Saturday, since 2022)
+            || us.is_juneteenth(date, true)
+
+            // Independence Day (Monday if Sunday or Friday if Saturday)
+            || ((d == 4 || (d == 5 && wd == Weekday::Monday) || (d == 3 && wd == Weekday::Friday)) && m == Month::July)
+
+            // Labor Day (first Monday in September)
+            || us.is_labor_day(date)
+
+            // Columbus Day (second Monday in October)
+            || us.is_columbus_day(date)
+
+            // Veteran's Day (Monday if Sunday, not moved if on Saturday)
+            || self.is_veterans_day(date)
+
+            // Thanksgiving Day (fourth Thursday in November)
+            || ((22..=28).contains(&d) && wd == Weekday::Thursday && m == Month::November)
+
+            // Christmas (Monday if Sunday or Friday if Saturday)
+            || ((d == 25 || (d == 26 && wd == Weekday::Monday) || (d == 24 && wd == Weekday::Friday)) && m == Month::December)
+
+            // Special closures
+            || SIFMA_SPECIAL_CLOSURES.contains(&date)
+        ) {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl EarlyClose for UnitedStatesGovernmentBondCalendar {
+    fn early_close(&self, date: Date) -> Option<Time> {
+        let (y, m, d, wd, yd, em) = unpack_date(date, false);
+
+        if !self.is_business_day(date) {
+            return None;
+        }
+
+        // Good Friday, when the employment report is released
+        // President Carter's funeral
+        // Hurricane Sandy
+        if (yd == em - 3 && SIFMA_GOOD_FRIDAY_EARLY_CLOSE_YEARS.contains(&y))
+            || date == date!(2025 - 01 - 09)
+            || date == date!(2012 - 10 - 29)
+        {
+            return Some(time!(12:00));
+        }
+
+        if (
+            // Day after Thanksgiving Day
+            ((23..=29).contains(&d) && wd == Weekday::Friday && m == Month::November)
+
+            // Christmas Eve and New Year's Eve
+            || ((d == 24 || d == 31) && m == Month::December)
+
+            // Business day before a holiday
+            || self.is_holiday_eve(date)
+        ) {
+            return Some(SIFMA_EARLY_CLOSE);
+        }
+
+        None
+    }
+}
+
+impl UnitedStatesGovernmentBondCalendar {
+    /// Create a new instance of the US Government Bond (SIFMA) calendar.
+    pub fn new() -> Self {
+        UnitedStatesGovernmentBondCalendar
+    }
+
+    fn is_veterans_day(&self, date: Date) -> bool {
+        let (y, m, d, wd, _, _) = unpack_date(date, false);
+
+        if (y <= 1970 || y >= 1978) {
+            // November 11th, moved to Monday if on Sunday
+            (d == 11 || (d == 12 && wd == Weekday::Monday)) && m == Month::November
+        } else {
+            // fourth Monday in October
+            UnitedStatesCalendar.is_veterans_day(date)
+        }
+    }
+
+    /// Check if the next weekday is a scheduled holiday with an early close
+    /// on the day before (all but Thanksgiving Day and Juneteenth).
+    fn is_holiday_eve(&self, date: Date) -> bool {
+        let us = UnitedStatesCalendar;
+
+        let mut next = date.next_day().unwrap();
+        while is_weekend(next) {
+            next = next.next_day().unwrap();
+        }
+
+        let (_, m, d, wd, _, _) = unpack_date(next, false);
+        let is_thanksgiving =
+            (22..=28).contains(&d) && wd == Weekday::Thursday && m == Month::November;
+
+        self.is_holiday(next)
+            && !SIFMA_SPECIAL_CLOSURES.contains(&next)
+            && !us.is_juneteenth(next, true)
+            && !is_thanksgiving
+    }
+}
+
+impl Calendar for FederalReserveCalendar {
+    fn name(&self) -> &'static str {
+        "Federal Reserve"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let (y, m, d, wd, _, _) = unpack_date(date, false);
+        let us = UnitedStatesCalendar;
+
+        // the holiday itself, or the following Monday if on a Sunday
+        let observed = |day: u8, month: Month| {
+            (d == day || (d == day + 1 && wd == Weekday::Monday)) && m == month
+        };
+
+        if (
+            // New Year's Day
+            observed(1, Month::January)
+
+            // Martin Luther King's birthday (third Monday in January)
+            || ((15..=21).contains(&d) && wd == Weekday::Monday && m == Month::January && y >= 1983)
+
+            // Washington's birthday (third Monday in February)
+            || us.is_washington_birthday(date)
+
+            // Memorial Day (last Monday in May)
+            || us.is_memorial_day(date)
+
+            // Juneteenth (since 2022)
+            || (observed(19, Month::June) && y >= 2022)
+
+            // Independence Day
+            || observed(4, Month::July)
+
+            // Labor Day (first Monday in September)
+            || us.is_labor_day(date)
+
+            // Columbus Day (second Monday in October)
+            || us.is_columbus_day(date)
+
+            // Veteran's Day (fourth Monday in October from 1971 to 1977)
+            || (observed(11, Month::November) && !(1971..=1977).contains(&y))
+            || ((1971..=1977).contains(&y) && us.is_veterans_day(date))
+
+            // Thanksgiving Day (fourth Thursday in November)
+            || ((22..=28).contains(&d) && wd == Weekday::Thursday && m == Month::November)
+
+            // Christmas
+            || observed(25, Month::December)
+        ) {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl FederalReserveCalendar {
+    /// Create a new instance of the Federal Reserve calendar.
+    pub fn new() -> Self {
+        FederalReserveCalendar
+    }
+}
+
+impl Calendar for SofrCalendar {
+    fn name(&self) -> &'static str {
+        "SOFR"
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        let (_, _, _, _, yd, em) = unpack_date(date, false);
+
+        // Good Friday, or a government bond holiday
+        yd == em - 3 || UnitedStatesGovernmentBondCalendar.is_holiday(date)
+    }
+}
+
+impl SofrCalendar {
+    /// Create a new instance of the SOFR fixing calendar.
+    pub fn new() -> Self {
+        SofrCalendar
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // UNIT TESTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -218,4 +599,181 @@ mod test_united_states {
         assert!(calendar.is_business_day(regular_day2));
         assert!(calendar.is_business_day(regular_day3));
     }
+
+    // Test to verify the NYSE holidays that differ from the national calendar.
+    #[test]
+    fn test_nyse_holidays() {
+        let calendar = NyseCalendar;
+        assert_eq!(calendar.name(), "New York Stock Exchange");
+
+        // Good Friday
+        assert!(!calendar.is_business_day(date!(2023 - 04 - 07)));
+        assert!(!calendar.is_business_day(date!(2024 - 03 - 29)));
+
+        // Columbus Day and Veterans Day are trading days
+        assert!(calendar.is_business_day(date!(2023 - 10 - 09)));
+        assert!(calendar.is_business_day(date!(2023 - 11 - 10)));
+
+        // New Year's Day on a Saturday is not moved to Friday
+        assert!(calendar.is_business_day(date!(2021 - 12 - 31)));
+
+        // Juneteenth is only observed since 2022
+        assert!(calendar.is_business_day(date!(2021 - 06 - 18)));
+        assert!(!calendar.is_business_day(date!(2022 - 06 - 20)));
+
+        // Presidential election days until 1980
+        assert!(!calendar.is_business_day(date!(1980 - 11 - 04)));
+        assert!(calendar.is_business_day(date!(1984 - 11 - 06)));
+    }
+
+    // Test to verify the NYSE special closures.
+    #[test]
+    fn test_nyse_special_closures() {
+        let calendar = NyseCalendar;
+
+        assert!(!calendar.is_business_day(date!(2001 - 09 - 11)));
+        assert!(!calendar.is_business_day(date!(2001 - 09 - 14)));
+        assert!(calendar.is_business_day(date!(2001 - 09 - 17)));
+        assert!(!calendar.is_business_day(date!(2012 - 10 - 29)));
+        assert!(!calendar.is_business_day(date!(2012 - 10 - 30)));
+        assert!(!calendar.is_business_day(date!(2018 - 12 - 05)));
+        assert!(!calendar.is_business_day(date!(2025 - 01 - 09)));
+    }
+
+    // Test to verify the NYSE early closes.
+    #[test]
+    fn test_nyse_early_closes() {
+        let calendar = NyseCalendar;
+
+        assert_eq!(
+            calendar.early_close(date!(2023 - 07 - 03)),
+            Some(time!(13:00))
+        );
+        assert_eq!(
+            calendar.early_close(date!(2023 - 11 - 24)),
+            Some(time!(13:00))
+        );
+        assert_eq!(
+            calendar.early_close(date!(2024 - 12 - 24)),
+            Some(time!(13:00))
+        );
+
+        // July 3rd is a holiday when Independence Day falls on a Saturday
+        assert_eq!(calendar.early_close(date!(2020 - 07 - 03)), None);
+        assert_eq!(calendar.early_close(date!(2023 - 07 - 05)), None);
+
+        assert_eq!(
+            calendar.all_early_closes_between(date!(2024 - 01 - 01), date!(2024 - 12 - 31)),
+            vec![
+                (date!(2024 - 07 - 03), time!(13:00)),
+                (date!(2024 - 11 - 29), time!(13:00)),
+                (date!(2024 - 12 - 24), time!(13:00)),
+            ]
+        );
+    }
+
+    // Test to verify the SIFMA government bond holidays.
+    #[test]
+    fn test_government_bond_holidays() {
+        let calendar = UnitedStatesGovernmentBondCalendar;
+        assert_eq!(calendar.name(), "United States Government Bond (SIFMA)");
+
+        // Good Friday is a full close in most years
+        assert!(!calendar.is_business_day(date!(2024 - 03 - 29)));
+        assert!(calendar.is_business_day(date!(2023 - 04 - 07)));
+
+        // Columbus Day and Veterans Day
+        assert!(!calendar.is_business_day(date!(2023 - 10 - 09)));
+        assert!(!calendar.is_business_day(date!(2024 - 11 - 11)));
+
+        // Veterans Day on a Saturday is not moved to Friday
+        assert!(calendar.is_business_day(date!(2023 - 11 - 10)));
+
+        // Independence Day on a Saturday is moved to Friday
+        assert!(!calendar.is_business_day(date!(2020 - 07 - 03)));
+
+        // Special closures
+        assert!(!calendar.is_business_day(date!(2012 - 10 - 30)));
+        assert!(!calendar.is_business_day(date!(2018 - 12 - 05)));
+    }
+
+    // Test to verify the SIFMA government bond early closes.
+    #[test]
+    fn test_government_bond_early_closes() {
+        let calendar = UnitedStatesGovernmentBondCalendar;
+
+        // Good Friday, 2023
+        assert_eq!(
+            calendar.early_close(date!(2023 - 04 - 07)),
+            Some(time!(12:00))
+        );
+
+        // Day before Good Friday, 2024
+        assert_eq!(
+            calendar.early_close(date!(2024 - 03 - 28)),
+            Some(time!(14:00))
+        );
+
+        // Friday before Memorial Day, 2024
+        assert_eq!(
+            calendar.early_close(date!(2024 - 05 - 24)),
+            Some(time!(14:00))
+        );
+
+        // Day after Thanksgiving, Christmas Eve and New Year's Eve
+        assert_eq!(
+            calendar.early_close(date!(2024 - 11 - 29)),
+            Some(time!(14:00))
+        );
+        assert_eq!(
+            calendar.early_close(date!(2024 - 12 - 24)),
+            Some(time!(14:00))
+        );
+        assert_eq!(
+            calendar.early_close(date!(2024 - 12 - 31)),
+            Some(time!(14:00))
+        );
+
+        // No early close before Thanksgiving Day
+        assert_eq!(calendar.early_close(date!(2024 - 11 - 27)), None);
+        assert_eq!(calendar.early_close(date!(2024 - 11 - 20)), None);
+    }
+
+    // Test to verify that the Federal Reserve does not observe Saturday holidays on Friday.
+    #[test]
+    fn test_federal_reserve_holidays() {
+        let calendar = FederalReserveCalendar;
+        assert_eq!(calendar.name(), "Federal Reserve");
+
+        // Independence Day 2020 and Christmas 2021 fell on a Saturday
+        assert!(calendar.is_business_day(date!(2020 - 07 - 03)));
+        assert!(calendar.is_business_day(date!(2021 - 12 - 24)));
+        assert!(!UnitedStatesCalendar.is_business_day(date!(2020 - 07 - 03)));
+
+        // Sunday holidays are observed on Monday
+        assert!(!calendar.is_business_day(date!(2022 - 12 - 26)));
+        assert!(!calendar.is_business_day(date!(2022 - 06 - 20)));
+
+        // Columbus Day and Veterans Day
+        assert!(!calendar.is_business_day(date!(2023 - 10 - 09)));
+        assert!(!calendar.is_business_day(date!(2022 - 11 - 11)));
+
+        // Good Friday is a business day
+        assert!(calendar.is_business_day(date!(2024 - 03 - 29)));
+    }
+
+    // Test to verify the SOFR fixing calendar.
+    #[test]
+    fn test_sofr_holidays() {
+        let calendar = SofrCalendar;
+        assert_eq!(calendar.name(), "SOFR");
+
+        // Good Friday, even when the bond market has an early close
+        assert!(!calendar.is_business_day(date!(2023 - 04 - 07)));
+        assert!(!calendar.is_business_day(date!(2024 - 03 - 29)));
+
+        // Government bond holidays
+        assert!(!calendar.is_business_day(date!(2023 - 10 - 09)));
+        assert!(calendar.is_business_day(date!(2023 - 11 - 10)));
+    }
 }
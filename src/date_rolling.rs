@@ -11,10 +11,10 @@
 
 use crate::{
     calendar::Calendar,
-    utilities::{next_business_day, previous_business_day},
+    utilities::{last_day_of_month, next_business_day, previous_business_day},
 };
 use std::fmt;
-use time::Date;
+use time::{Date, Duration};
 
 /// Date rolling business day conventions.
 ///
@@ -30,6 +30,10 @@ pub enum DateRollingConvention {
     /// Actual: paid on the actual day, even if it is a non-business day.
     Actual,
 
+    /// Unadjusted: identical to `Actual`, the date is not adjusted.
+    /// Provided since this is the name used in the ISDA definitions.
+    Unadjusted,
+
     /// Following business day: the payment date is rolled to the next business day.
     Following,
 
@@ -53,7 +57,31 @@ pub enum DateRollingConvention {
     /// Modified Rolling business day: the payment date is rolled to the next
     /// business day. The adjusted week date is used for the next coupon date.
     /// So adjustments are cumulative (excluding month change).
+    ///
+    /// Since the adjustment depends on the previous date, this convention is
+    /// only cumulative when rolling a whole schedule via `roll_dates`.
+    /// A single date is rolled as per `ModifiedFollowing`.
     ModifiedRolling,
+
+    /// Nearest business day: the payment date is rolled to the nearest
+    /// business day. If the previous and next business days are equally
+    /// far away, the next business day is chosen.
+    /// So a Sunday is rolled to the Monday, and a Saturday to the Friday.
+    Nearest,
+
+    /// Half-month modified following business day: the payment date is rolled
+    /// to the next business day, unless doing so would cause the payment to
+    /// cross the middle of the month (the 15th) or the end of the month,
+    /// in which case the payment date is rolled to the previous business day.
+    HalfMonthModifiedFollowing,
+
+    /// End of month: the payment date is moved to the last business day
+    /// of its month, whatever its day in the month.
+    EndOfMonth,
+
+    /// End of month unadjusted: the payment date is moved to the last
+    /// calendar day of its month, even if it is a non-business day.
+    EndOfMonthUnadjusted,
 }
 
 /// Date roller trait for rolling coupon/payment dates according to a given convention.
@@ -62,6 +90,9 @@ pub trait DateRoller {
     fn roll_date(&self, date: Date, convention: &DateRollingConvention) -> Date;
 
     /// Roll a list of dates according to the given convention.
+    ///
+    /// The dates are assumed to be in chronological order, which matters
+    /// for `ModifiedRolling` where each adjustment carries forward to the next date.
     fn roll_dates(&self, dates: &[Date], convention: &DateRollingConvention) -> Vec<Date>;
 }
 
@@ -72,16 +103,25 @@ where
     #[rustfmt::skip]
     fn roll_date(&self, date: Date, convention: &DateRollingConvention) -> Date {
         match convention {
-            DateRollingConvention::Actual               => DateRollingConvention::roll_date_actual(date, self),
-            DateRollingConvention::Following            => DateRollingConvention::roll_date_following(date, self),
-            DateRollingConvention::ModifiedFollowing    => DateRollingConvention::roll_date_modified_following(date, self),
-            DateRollingConvention::Preceding            => DateRollingConvention::roll_date_preceding(date, self),
-            DateRollingConvention::ModifiedPreceding    => DateRollingConvention::roll_date_modified_preceding(date, self),
-            DateRollingConvention::ModifiedRolling      => DateRollingConvention::roll_date_modified_rolling(date, self),
+            DateRollingConvention::Actual                     => DateRollingConvention::roll_date_actual(date, self),
+            DateRollingConvention::Unadjusted                 => DateRollingConvention::roll_date_actual(date, self),
+            DateRollingConvention::Following                  => DateRollingConvention::roll_date_following(date, self),
+            DateRollingConvention::ModifiedFollowing          => DateRollingConvention::roll_date_modified_following(date, self),
+            DateRollingConvention::Preceding                  => DateRollingConvention::roll_date_preceding(date, self),
+            DateRollingConvention::ModifiedPreceding          => DateRollingConvention::roll_date_modified_preceding(date, self),
+            DateRollingConvention::ModifiedRolling            => DateRollingConvention::roll_date_modified_rolling(date, self),
+            DateRollingConvention::Nearest                    => DateRollingConvention::roll_date_nearest(date, self),
+            DateRollingConvention::HalfMonthModifiedFollowing => DateRollingConvention::roll_date_half_month_modified_following(date, self),
+            DateRollingConvention::EndOfMonth                 => DateRollingConvention::roll_date_end_of_month(date, self),
+            DateRollingConvention::EndOfMonthUnadjusted       => DateRollingConvention::roll_date_end_of_month_unadjusted(date, self),
         }
     }
 
     fn roll_dates(&self, dates: &[Date], convention: &DateRollingConvention) -> Vec<Date> {
+        if let DateRollingConvention::ModifiedRolling = convention {
+            return DateRollingConvention::roll_dates_modified_rolling(dates, self);
+        }
+
         dates
             .iter()
             .map(|&date| self.roll_date(date, convention))
@@ -100,12 +140,17 @@ impl fmt::Display for DateRollingConvention {
     #[rustfmt::skip]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Actual                => write!(f, "Actual"),
-            Self::Following             => write!(f, "Following"),
-            Self::ModifiedFollowing     => write!(f, "Modified Following"),
-            Self::Preceding             => write!(f, "Preceding"),
-            Self::ModifiedPreceding     => write!(f, "Modified Preceding"),
-            Self::ModifiedRolling       => write!(f, "Modified Rolling"),
+            Self::Actual                     => write!(f, "Actual"),
+            Self::Unadjusted                 => write!(f, "Unadjusted"),
+            Self::Following                  => write!(f, "Following"),
+            Self::ModifiedFollowing          => write!(f, "Modified Following"),
+            Self::Preceding                  => write!(f, "Preceding"),
+            Self::ModifiedPreceding          => write!(f, "Modified Preceding"),
+            Self::ModifiedRolling            => write!(f, "Modified Rolling"),
+            Self::Nearest                    => write!(f, "Nearest"),
+            Self::HalfMonthModifiedFollowing => write!(f, "Half-Month Modified Following"),
+            Self::EndOfMonth                 => write!(f, "End of Month"),
+            Self::EndOfMonthUnadjusted       => write!(f, "End of Month Unadjusted"),
         }
     }
 }
@@ -149,13 +194,257 @@ impl DateRollingConvention {
     }
 
     /// Adjust (roll) the date according: Modified rolling convention.
+    /// With no previous adjustment to carry forward, this is modified following.
     fn roll_date_modified_rolling<C: Calendar>(date: Date, calendar: &C) -> Date {
-        let mut new_date = date;
+        Self::roll_date_modified_following(date, calendar)
+    }
+
+    /// Adjust (roll) a schedule of dates according: Modified rolling convention.
+    ///
+    /// The adjustment applied to each date (the number of days it was moved)
+    /// is carried forward to the next unadjusted date before rolling it.
+    /// If the carried adjustment, or the roll itself, would move the date into
+    /// another month, the original date is rolled as per modified following
+    /// instead, and the cumulative adjustment is reset accordingly.
+    fn roll_dates_modified_rolling<C: Calendar>(dates: &[Date], calendar: &C) -> Vec<Date> {
+        let mut rolled_dates = Vec::with_capacity(dates.len());
+        let mut adjustment = Duration::ZERO;
+
+        for &date in dates {
+            let new_date = match date.checked_add(adjustment) {
+                Some(shifted) if shifted.month() == date.month() => {
+                    let rolled = next_business_day(shifted, calendar);
+
+                    match rolled.month() == date.month() {
+                        true => rolled,
+                        false => Self::roll_date_modified_following(date, calendar),
+                    }
+                }
+                _ => Self::roll_date_modified_following(date, calendar),
+            };
+
+            adjustment = new_date - date;
+            rolled_dates.push(new_date);
+        }
 
-        while !calendar.is_business_day(new_date) {
-            new_date = new_date.next_day().unwrap();
+        rolled_dates
+    }
+
+    /// Adjust (roll) the date according: Nearest convention.
+    fn roll_date_nearest<C: Calendar>(date: Date, calendar: &C) -> Date {
+        if calendar.is_business_day(date) {
+            return date;
+        }
+
+        let next = next_business_day(date, calendar);
+        let previous = previous_business_day(date, calendar);
+
+        match (next - date) <= (date - previous) {
+            true => next,
+            false => previous,
+        }
+    }
+
+    /// Adjust (roll) the date according: Half-month modified following convention.
+    fn roll_date_half_month_modified_following<C: Calendar>(date: Date, calendar: &C) -> Date {
+        let new_date = Self::roll_date_modified_following(date, calendar);
+
+        if date.day() <= 15 && new_date.day() > 15 && new_date.month() == date.month() {
+            return previous_business_day(date, calendar);
         }
 
         new_date
     }
+
+    /// Adjust (roll) the date according: End of month convention.
+    fn roll_date_end_of_month<C: Calendar>(date: Date, calendar: &C) -> Date {
+        previous_business_day(last_day_of_month(date), calendar)
+    }
+
+    /// Adjust (roll) the date according: End of month unadjusted convention.
+    fn roll_date_end_of_month_unadjusted<C: Calendar>(date: Date, _calendar: &C) -> Date {
+        last_day_of_month(date)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_date_rolling {
+    use super::*;
+    use crate::countries::europe::united_kingdom::UnitedKingdomCalendar;
+    use crate::countries::north_america::united_states::UnitedStatesCalendar;
+    use time::macros::date;
+
+    #[test]
+    fn test_unadjusted_is_actual() {
+        let calendar = UnitedStatesCalendar;
+        let sat = date!(2023 - 09 - 30);
+
+        assert_eq!(
+            calendar.roll_date(sat, &DateRollingConvention::Unadjusted),
+            sat
+        );
+        assert_eq!(calendar.roll_date(sat, &DateRollingConvention::Actual), sat);
+    }
+
+    // 30 September 2023 is a Saturday.
+    #[test]
+    fn test_isda_following_and_preceding() {
+        let calendar = UnitedStatesCalendar;
+        let sat = date!(2023 - 09 - 30);
+
+        assert_eq!(
+            calendar.roll_date(sat, &DateRollingConvention::Following),
+            date!(2023 - 10 - 02)
+        );
+        assert_eq!(
+            calendar.roll_date(sat, &DateRollingConvention::ModifiedFollowing),
+            date!(2023 - 09 - 29)
+        );
+        assert_eq!(
+            calendar.roll_date(sat, &DateRollingConvention::Preceding),
+            date!(2023 - 09 - 29)
+        );
+    }
+
+    #[test]
+    fn test_nearest() {
+        let calendar = UnitedStatesCalendar;
+
+        // Saturday -> Friday.
+        assert_eq!(
+            calendar.roll_date(date!(2023 - 08 - 26), &DateRollingConvention::Nearest),
+            date!(2023 - 08 - 25)
+        );
+        // Sunday -> Monday.
+        assert_eq!(
+            calendar.roll_date(date!(2023 - 08 - 27), &DateRollingConvention::Nearest),
+            date!(2023 - 08 - 28)
+        );
+        // Sunday before Labor Day (Monday): equidistant, so the next business day.
+        assert_eq!(
+            calendar.roll_date(date!(2023 - 09 - 03), &DateRollingConvention::Nearest),
+            date!(2023 - 09 - 05)
+        );
+        // Business days are unchanged.
+        assert_eq!(
+            calendar.roll_date(date!(2023 - 08 - 24), &DateRollingConvention::Nearest),
+            date!(2023 - 08 - 24)
+        );
+    }
+
+    #[test]
+    fn test_half_month_modified_following() {
+        let calendar = UnitedKingdomCalendar;
+        let convention = DateRollingConvention::HalfMonthModifiedFollowing;
+
+        // Saturday 15th July 2023: following would cross the 15th.
+        assert_eq!(
+            calendar.roll_date(date!(2023 - 07 - 15), &convention),
+            date!(2023 - 07 - 14)
+        );
+        // Saturday 8th July 2023: following stays in the first half.
+        assert_eq!(
+            calendar.roll_date(date!(2023 - 07 - 08), &convention),
+            date!(2023 - 07 - 10)
+        );
+        // Saturday 29th July 2023: following stays in the month.
+        assert_eq!(
+            calendar.roll_date(date!(2023 - 07 - 29), &convention),
+            date!(2023 - 07 - 31)
+        );
+        // Saturday 30th September 2023: following would cross the month end.
+        assert_eq!(
+            calendar.roll_date(date!(2023 - 09 - 30), &convention),
+            date!(2023 - 09 - 29)
+        );
+    }
+
+    #[test]
+    fn test_end_of_month() {
+        let calendar = UnitedKingdomCalendar;
+
+        // 31st December 2023 is a Sunday; 26th-29th are business days after Boxing Day.
+        assert_eq!(
+            calendar.roll_date(date!(2023 - 12 - 15), &DateRollingConvention::EndOfMonth),
+            date!(2023 - 12 - 29)
+        );
+        assert_eq!(
+            calendar.roll_date(
+                date!(2023 - 12 - 15),
+                &DateRollingConvention::EndOfMonthUnadjusted
+            ),
+            date!(2023 - 12 - 31)
+        );
+        // Leap year February.
+        assert_eq!(
+            calendar.roll_date(date!(2024 - 02 - 10), &DateRollingConvention::EndOfMonth),
+            date!(2024 - 02 - 29)
+        );
+    }
+
+    #[test]
+    fn test_modified_rolling_is_cumulative() {
+        let calendar = UnitedStatesCalendar;
+        let dates = [
+            date!(2023 - 04 - 01), // Saturday -> Monday 3rd (+2 days).
+            date!(2023 - 05 - 01), // Carried to Wednesday 3rd (+2 days).
+            date!(2023 - 06 - 01), // Carried to Saturday 3rd -> Monday 5th (+4 days).
+            date!(2023 - 07 - 01), // Carried to Wednesday 5th (+4 days).
+        ];
+
+        assert_eq!(
+            calendar.roll_dates(&dates, &DateRollingConvention::ModifiedRolling),
+            vec![
+                date!(2023 - 04 - 03),
+                date!(2023 - 05 - 03),
+                date!(2023 - 06 - 05),
+                date!(2023 - 07 - 05),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_modified_rolling_month_boundary() {
+        let calendar = UnitedStatesCalendar;
+        let dates = [
+            date!(2023 - 07 - 01), // Saturday -> Monday 3rd (+2 days).
+            date!(2023 - 08 - 30), // Carried to 1st September: reset to Wednesday 30th.
+            date!(2023 - 09 - 29), // No adjustment to carry: Friday 29th.
+        ];
+
+        assert_eq!(
+            calendar.roll_dates(&dates, &DateRollingConvention::ModifiedRolling),
+            vec![
+                date!(2023 - 07 - 03),
+                date!(2023 - 08 - 30),
+                date!(2023 - 09 - 29)
+            ]
+        );
+
+        // A single date has nothing to carry forward.
+        assert_eq!(
+            calendar.roll_date(
+                date!(2023 - 09 - 30),
+                &DateRollingConvention::ModifiedRolling
+            ),
+            date!(2023 - 09 - 29)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(DateRollingConvention::Nearest.to_string(), "Nearest");
+        assert_eq!(
+            DateRollingConvention::HalfMonthModifiedFollowing.to_string(),
+            "Half-Month Modified Following"
+        );
+        assert_eq!(
+            DateRollingConvention::EndOfMonth.to_string(),
+            "End of Month"
+        );
+    }
 }
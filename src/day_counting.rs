@@ -11,10 +11,15 @@
 
 use crate::{
     calendar::Calendar,
-    utilities::{contains_leap_year, get_years_in_range, is_last_day_of_february, leap_year_count},
+    frequency::Frequency,
+    period::{Period, TimeUnit},
+    utilities::{get_years_in_range, is_last_day_of_february, last_day_of_month, leap_day_count},
 };
 use std::fmt::{self};
-use time::{Date, Duration, Month};
+use time::{
+    util::{days_in_year, is_leap_year},
+    Date, Duration, Month,
+};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, AND TRAITS
@@ -49,17 +54,19 @@ pub enum DayCountConvention {
     Actual_365_25,
 
     /// The 'Act/365 Actual' day count, which divides the actual number of days
-    /// by 366 if a leap day is contained, or by 365 if not.
+    /// by 366 if a leap day (29th February) is contained in the period, or by 365 if not.
     Actual_365_Actual,
 
     /// The 'Act/365F' day count, which divides the actual number of days by 365 (fixed).
     Actual_365_Fixed,
 
-    /// The 'Act/365L' day count, which divides the actual number of days by 365 or 366.
+    /// The 'Act/365L' day count, which divides the actual number of days by 366
+    /// if the end date falls in a leap year, or by 365 if not.
     Actual_365_Leap,
 
     /// The 'Act/Act AFB' day count, which divides the actual number of days by 366
-    /// if a leap day is contained, or by 365 if not, with additional rules for periods over one year.
+    /// if a leap day is contained, or by 365 if not. Periods over one year are split
+    /// into whole years counted back from the end date, plus the remaining days.
     Actual_Actual_AFB,
 
     /// The 'Act/Act ICMA' day count, which divides the actual number of days by
@@ -70,10 +77,10 @@ pub enum DayCountConvention {
     /// leap year by 366 and the actual number of days in a standard year by 365.
     Actual_Actual_ISDA,
 
-    /// The 'NL/360' day count, which divides the actual number of days omitting leap days by 360.
+    /// The 'NL/360' day count, which divides the actual number of days omitting leap days (29th February) by 360.
     NL_360,
 
-    /// The 'NL/365' day count, which divides the actual number of days omitting leap days by 365.
+    /// The 'NL/365' day count, which divides the actual number of days omitting leap days (29th February) by 365.
     NL_365,
 
     /// The '30/360 ISDA' day count, which treats input day-of-month 31 specially.
@@ -93,6 +100,37 @@ pub enum DayCountConvention {
 
     /// The '30U/360' day count, which treats input day-of-month 31 and end of February specially.
     Thirty_U_360,
+
+    /// The 'Business/252' day count, which divides the number of business days by 252.
+    /// Business days are counted from the start date (inclusive) to the end date (exclusive).
+    ///
+    /// This is the standard convention for Brazilian instruments, using the
+    /// ANBIMA calendar (see `BrazilCalendar`). It needs a calendar, so must be
+    /// computed via `DayCounter::day_count_factor`.
+    Business_252,
+}
+
+/// Context for day count conventions that depend on the instrument's schedule.
+///
+/// Some conventions cannot be computed from the accrual period alone:
+///     - '30E/360 ISDA' (and '30E/365') does not adjust the end of February
+///       when the end date is the termination date.
+///     - '30U/360' only applies its end of February rules under the end-of-month rule.
+///     - 'Act/365L' uses a different leap year rule for annual frequencies.
+///     - 'Act/Act ICMA' divides by the length of the coupon periods, which
+///       are rolled back from the termination date at the given frequency.
+///
+/// All other conventions ignore the context.
+#[derive(Debug, Clone, Copy)]
+pub struct DayCountContext {
+    /// The termination (maturity) date of the instrument.
+    pub termination_date: Option<Date>,
+
+    /// Whether the schedule follows the end-of-month rule.
+    pub end_of_month: bool,
+
+    /// The coupon frequency of the instrument.
+    pub frequency: Option<Frequency>,
 }
 
 /// `DayCounter` trait.
@@ -111,6 +149,15 @@ pub trait DayCounter {
     /// Compute the day count factor between two dates.
     fn day_count_factor(&self, date1: Date, date2: Date, convention: &DayCountConvention) -> f64;
 
+    /// Compute the day count factor between two dates, given the schedule context.
+    fn day_count_factor_with_context(
+        &self,
+        date1: Date,
+        date2: Date,
+        convention: &DayCountConvention,
+        context: &DayCountContext,
+    ) -> f64;
+
     /// Compute the number of calendar days between each date in a vector of dates.
     fn calendar_day_counts(&self, dates: &[Date]) -> Vec<i64>;
 
@@ -143,6 +190,7 @@ impl fmt::Display for DayCountConvention {
             Self::Thirty_E_365          => write!(f, "30 E / 365"),
             Self::Thirty_E_Plus_360     => write!(f, "30 E+ / 360"),
             Self::Thirty_U_360          => write!(f, "30 U / 360"),
+            Self::Business_252          => write!(f, "Business / 252"),
         }
     }
 }
@@ -217,6 +265,8 @@ where
 
     /// Computes the day count factor between two dates.
     ///
+    /// Calendar dependent conventions (such as `Business_252`) use this calendar.
+    ///
     /// # Arguments
     ///
     /// * `date1` - The first date.
@@ -237,10 +287,57 @@ where
     /// let calendar = AustraliaCalendar;
     /// let convention = DayCountConvention::Actual_365_Actual;
     ///
-    /// assert_eq!(calendar.day_count_factor(date1, date2, &convention), 0.997_267_759_562_841_5);
+    /// assert_eq!(calendar.day_count_factor(date1, date2, &convention), 1.0);
     /// ```
     fn day_count_factor(&self, date1: Date, date2: Date, convention: &DayCountConvention) -> f64 {
-        convention.day_count_factor(date1, date2)
+        self.day_count_factor_with_context(date1, date2, convention, &DayCountContext::default())
+    }
+
+    /// Computes the day count factor between two dates, given the schedule context.
+    ///
+    /// # Arguments
+    ///
+    /// * `date1` - The first date.
+    /// * `date2` - The second date.
+    /// * `convention` - The day count convention.
+    /// * `context` - The termination date, end-of-month rule and frequency.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::day_counting::{DayCountContext, DayCounter};
+    /// use calenda_rs::countries::europe::germany::GermanyCalendar;
+    /// use calenda_rs::day_counting::DayCountConvention;
+    ///
+    /// let date1 = date!(2023-08-31);
+    /// let date2 = date!(2024-02-29);
+    ///
+    /// let calendar = GermanyCalendar;
+    /// let convention = DayCountConvention::Thirty_E_360_ISDA;
+    /// let context = DayCountContext {
+    ///     termination_date: Some(date2),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(calendar.day_count_factor(date1, date2, &convention), 0.5);
+    /// assert_eq!(calendar.day_count_factor_with_context(date1, date2, &convention, &context), 179.0 / 360.0);
+    /// ```
+    fn day_count_factor_with_context(
+        &self,
+        date1: Date,
+        date2: Date,
+        convention: &DayCountConvention,
+        context: &DayCountContext,
+    ) -> f64 {
+        match convention {
+            DayCountConvention::Business_252 => {
+                DayCountConvention::day_count_factor_business_252(date1, date2, |date| {
+                    self.is_business_day(date)
+                })
+            }
+            _ => convention.day_count_factor_with_context(date1, date2, context),
+        }
     }
 
     /// Compute the number of calendar days between each date in a vector of dates.
@@ -324,7 +421,7 @@ where
     /// let date3 = date!(2025-01-01);
     ///
     /// let dates = &[date1, date2, date3];
-    /// let expected = vec![0.997_267_759_562_841_5, 1.0];
+    /// let expected = vec![1.0, 1.0];
     ///
     /// let calendar = AustraliaCalendar;
     /// let convention = DayCountConvention::Actual_365_Actual;
@@ -346,10 +443,38 @@ impl Default for DayCountConvention {
     }
 }
 
+impl Default for DayCountContext {
+    /// Default context: no termination date or frequency, with the end-of-month rule.
+    fn default() -> Self {
+        Self {
+            termination_date: None,
+            end_of_month: true,
+            frequency: None,
+        }
+    }
+}
+
 impl DayCountConvention {
     /// Entry point for day count factor calculation.
-    #[rustfmt::skip]
+    ///
+    /// Schedule dependent conventions use the default `DayCountContext`.
+    ///
+    /// # Panics
+    ///
+    /// Panics for calendar dependent conventions (`Business_252`),
+    /// which must be computed with a calendar via `DayCounter::day_count_factor`.
     pub fn day_count_factor(&self, start_date: Date, end_date: Date) -> f64 {
+        self.day_count_factor_with_context(start_date, end_date, &DayCountContext::default())
+    }
+
+    /// Entry point for day count factor calculation, given the schedule context.
+    ///
+    /// # Panics
+    ///
+    /// Panics for calendar dependent conventions (`Business_252`),
+    /// which must be computed with a calendar via `DayCounter::day_count_factor_with_context`.
+    #[rustfmt::skip]
+    pub fn day_count_factor_with_context(&self, start_date: Date, end_date: Date, context: &DayCountContext) -> f64 {
         match self {
             Self::One_One               => Self::day_count_factor_one_one(start_date, end_date),
             Self::Actual_360            => Self::day_count_factor_actual_360(start_date, end_date),
@@ -357,18 +482,19 @@ impl DayCountConvention {
             Self::Actual_365_25         => Self::day_count_factor_actual_365_25(start_date, end_date),
             Self::Actual_365_Actual     => Self::day_count_factor_actual_365_actual(start_date, end_date),
             Self::Actual_365_Fixed      => Self::day_count_factor_actual_365_fixed(start_date, end_date),
-            Self::Actual_365_Leap       => Self::day_count_factor_actual_365_leap(start_date, end_date),
+            Self::Actual_365_Leap       => Self::day_count_factor_actual_365_leap(start_date, end_date, context),
             Self::Actual_Actual_AFB     => Self::day_count_factor_actual_actual_afb(start_date, end_date),
-            Self::Actual_Actual_ICMA    => Self::day_count_factor_actual_actual_icma(start_date, end_date),
+            Self::Actual_Actual_ICMA    => Self::day_count_factor_actual_actual_icma(start_date, end_date, context),
             Self::Actual_Actual_ISDA    => Self::day_count_factor_actual_actual_isda(start_date, end_date),
             Self::NL_360                => Self::day_count_factor_nl_360(start_date, end_date),
             Self::NL_365                => Self::day_count_factor_nl_365(start_date, end_date),
             Self::Thirty_360_ISDA       => Self::day_count_factor_thirty_360_isda(start_date, end_date),
             Self::Thirty_E_360          => Self::day_count_factor_thirty_e_360(start_date, end_date),
-            Self::Thirty_E_360_ISDA     => Self::day_count_factor_thirty_e_360_isda(start_date, end_date),
-            Self::Thirty_E_365          => Self::day_count_factor_thirty_e_365(start_date, end_date),
+            Self::Thirty_E_360_ISDA     => Self::day_count_factor_thirty_e_360_isda(start_date, end_date, context),
+            Self::Thirty_E_365          => Self::day_count_factor_thirty_e_365(start_date, end_date, context),
             Self::Thirty_E_Plus_360     => Self::day_count_factor_thirty_e_plus_360(start_date, end_date),
-            Self::Thirty_U_360          => Self::day_count_factor_thirty_u_360(start_date, end_date),
+            Self::Thirty_U_360          => Self::day_count_factor_thirty_u_360(start_date, end_date, context),
+            Self::Business_252          => panic!("Business/252 needs a calendar: use DayCounter::day_count_factor"),
         }
     }
 
@@ -394,9 +520,11 @@ impl DayCountConvention {
 
     // Actual/365 Actual day count factor calculation.
     fn day_count_factor_actual_365_actual(start_date: Date, end_date: Date) -> f64 {
-        match contains_leap_year(start_date, end_date) {
-            true => (end_date - start_date).whole_days() as f64 / 366.0,
-            false => (end_date - start_date).whole_days() as f64 / 365.0,
+        let day_count = (end_date - start_date).whole_days() as f64;
+
+        match leap_day_count(start_date, end_date) > 0 {
+            true => day_count / 366.0,
+            false => day_count / 365.0,
         }
     }
 
@@ -406,79 +534,169 @@ impl DayCountConvention {
     }
 
     // Actual/365L day count factor calculation.
-    fn day_count_factor_actual_365_leap(start_date: Date, end_date: Date) -> f64 {
-        match contains_leap_year(start_date, end_date) {
-            true => (end_date - start_date).whole_days() as f64 / 366.0,
-            false => (end_date - start_date).whole_days() as f64 / 365.0,
+    // For annual frequencies, 366 is used if the period contains the 29th February.
+    // Otherwise (or without a frequency), 366 is used if the end date is in a leap year.
+    fn day_count_factor_actual_365_leap(
+        start_date: Date,
+        end_date: Date,
+        context: &DayCountContext,
+    ) -> f64 {
+        let day_count = (end_date - start_date).whole_days() as f64;
+
+        let is_leap = match context.frequency {
+            Some(Frequency::Annually) => leap_day_count(start_date, end_date) > 0,
+            _ => is_leap_year(end_date.year()),
+        };
+
+        match is_leap {
+            true => day_count / 366.0,
+            false => day_count / 365.0,
         }
     }
 
     // Actual/Actual AFB day count factor calculation.
+    // Whole years are counted back from the end date, one year at a time
+    // (a 28th February moving to the 29th in leap years, as in QuantLib),
+    // then the remaining days are divided by 366 if they contain a 29th February,
+    // or 365 if not.
     fn day_count_factor_actual_actual_afb(start_date: Date, end_date: Date) -> f64 {
-        let (y1, _y2) = (start_date.year(), end_date.year());
-        let (_m1, m2) = (start_date.month(), end_date.month());
-        let (_d1, d2) = (start_date.day(), end_date.day());
-
-        let stub_date = if Date::from_calendar_date(y1, m2, d2).unwrap() < start_date {
-            Date::from_calendar_date(y1 + 1, m2, d2).unwrap()
-        } else {
-            Date::from_calendar_date(y1, m2, d2).unwrap()
-        };
+        if end_date < start_date {
+            return -Self::day_count_factor_actual_actual_afb(end_date, start_date);
+        }
 
-        let initial_stub_days = (stub_date - start_date).whole_days() as f64;
-        // let final_stub_days = (end_date - stub_date).whole_days() as f64;
-        let final_stub_years = (end_date.year() - stub_date.year()) as f64;
-        let initial_stub_contains_leap = contains_leap_year(start_date, stub_date);
+        let mut years = 0;
+        let mut stub_end = end_date;
 
-        match initial_stub_contains_leap {
-            true => final_stub_years + initial_stub_days / 366.0,
-            false => (end_date - start_date).whole_days() as f64 / 365.0,
+        loop {
+            let mut previous = Self::shift_years(stub_end, -1);
+
+            if previous.month() == Month::February
+                && previous.day() == 28
+                && is_leap_year(previous.year())
+            {
+                previous += Duration::DAY;
+            }
+
+            if previous < start_date {
+                break;
+            }
+
+            years += 1;
+            stub_end = previous;
+        }
+
+        let stub_days = (stub_end - start_date).whole_days() as f64;
+
+        // 29th February in [start, stub end).
+        match leap_day_count(start_date - Duration::days(1), stub_end - Duration::days(1)) > 0 {
+            true => years as f64 + stub_days / 366.0,
+            false => years as f64 + stub_days / 365.0,
         }
     }
 
     // Actual/Actual ICMA day count factor calculation.
-    fn day_count_factor_actual_actual_icma(_start_date: Date, _end_date: Date) -> f64 {
-        todo!()
-    }
+    // The coupon periods are rolled back from the termination date (or the end date)
+    // at the given frequency, and the days in each are divided by the frequency
+    // times the length of that period. Without a (monthly based) frequency,
+    // the period is treated as its own reference period, rounded to whole months
+    // (as in QuantLib).
+    fn day_count_factor_actual_actual_icma(
+        start_date: Date,
+        end_date: Date,
+        context: &DayCountContext,
+    ) -> f64 {
+        if end_date < start_date {
+            return -Self::day_count_factor_actual_actual_icma(end_date, start_date, context);
+        }
 
-    // Actual/Actual ISDA day count factor calculation.
-    fn day_count_factor_actual_actual_isda(start_date: Date, end_date: Date) -> f64 {
-        let years = get_years_in_range(start_date, end_date);
-        let year_day_counts = get_years_in_range(start_date, end_date);
+        let day_count = (end_date - start_date).whole_days() as f64;
 
-        let start_day_count = (Date::from_calendar_date(start_date.year(), Month::December, 31)
-            .unwrap()
-            - start_date)
-            .whole_days() as f64;
+        let months = match context.frequency.and_then(|frequency| frequency.tenor()) {
+            Some(Period {
+                length,
+                unit: TimeUnit::Months,
+            }) if length > 0 => length,
+            Some(Period {
+                length,
+                unit: TimeUnit::Years,
+            }) if length > 0 => 12 * length,
+            _ => {
+                let months = (12.0 * day_count / 365.0).round();
+
+                return match months == 0.0 {
+                    true => {
+                        let reference_end = Self::shift_years(start_date, 1);
+
+                        day_count / (reference_end - start_date).whole_days() as f64
+                    }
+                    false => months / 12.0,
+                };
+            }
+        };
 
-        let end_day_count = (end_date
-            - Date::from_calendar_date(end_date.year(), Month::January, 1).unwrap())
-        .whole_days() as f64;
+        let frequency = 12.0 / months as f64;
+        let anchor = context.termination_date.unwrap_or(end_date).max(end_date);
+        let end_of_month = context.end_of_month && anchor == last_day_of_month(anchor);
+        let tenor = Period::new(months, TimeUnit::Months);
 
         let mut dcf = 0.0;
+        let mut period_end = anchor;
+        let mut periods = 1;
+
+        while period_end > start_date {
+            let period_start = match tenor.advance(anchor, -periods) {
+                date if end_of_month => last_day_of_month(date),
+                date => date,
+            };
+            let overlap = (end_date.min(period_end) - start_date.max(period_start)).whole_days();
 
-        for _ in 1..years.len() {
-            dcf += 1.0;
+            if overlap > 0 {
+                let period_length = (period_end - period_start).whole_days() as f64;
+
+                dcf += overlap as f64 / (frequency * period_length);
+            }
+
+            period_end = period_start;
+            periods += 1;
         }
 
-        dcf + start_day_count / year_day_counts[0] as f64
-            + end_day_count / year_day_counts[years.len() - 1] as f64
+        dcf
+    }
+
+    // Actual/Actual ISDA day count factor calculation.
+    // The days falling in each calendar year are divided by the length of that year.
+    fn day_count_factor_actual_actual_isda(start_date: Date, end_date: Date) -> f64 {
+        if end_date < start_date {
+            return -Self::day_count_factor_actual_actual_isda(end_date, start_date);
+        }
+
+        get_years_in_range(start_date, end_date)
+            .iter()
+            .map(|&year| {
+                let year_start = Date::from_calendar_date(year, Month::January, 1).unwrap();
+                let year_end = Date::from_calendar_date(year + 1, Month::January, 1).unwrap();
+
+                let days = (end_date.min(year_end) - start_date.max(year_start)).whole_days();
+
+                days as f64 / days_in_year(year) as f64
+            })
+            .sum()
     }
 
     // NL/360 day count factor calculation.
     fn day_count_factor_nl_360(start_date: Date, end_date: Date) -> f64 {
         let day_count = (end_date - start_date).whole_days() as f64;
-        let leap_years = leap_year_count(start_date, end_date) as f64;
+        let leap_days = leap_day_count(start_date, end_date) as f64;
 
-        (day_count - leap_years) / 360.0
+        (day_count - leap_days) / 360.0
     }
 
     // NL/365 day count factor calculation.
     fn day_count_factor_nl_365(start_date: Date, end_date: Date) -> f64 {
         let day_count = (end_date - start_date).whole_days() as f64;
-        let leap_years = leap_year_count(start_date, end_date) as f64;
+        let leap_days = leap_day_count(start_date, end_date) as f64;
 
-        (day_count - leap_years) / 365.0
+        (day_count - leap_days) / 365.0
     }
 
     // 30/360 ISDA day count factor calculation.
@@ -514,15 +732,22 @@ impl DayCountConvention {
     }
 
     // 30E/360 ISDA day count factor calculation.
-    fn day_count_factor_thirty_e_360_isda(start_date: Date, end_date: Date) -> f64 {
+    // The end of February is not adjusted if the end date is the termination date.
+    fn day_count_factor_thirty_e_360_isda(
+        start_date: Date,
+        end_date: Date,
+        context: &DayCountContext,
+    ) -> f64 {
         let (y1, m1, mut d1) = Self::thirty_360_unpack_date(start_date);
         let (y2, m2, mut d2) = Self::thirty_360_unpack_date(end_date);
 
+        let is_termination_date = context.termination_date == Some(end_date);
+
         if d1 == 31 || is_last_day_of_february(start_date) {
             d1 = 30;
         }
 
-        if d2 == 31 || is_last_day_of_february(end_date) {
+        if d2 == 31 || (is_last_day_of_february(end_date) && !is_termination_date) {
             d2 = 30;
         }
 
@@ -530,15 +755,22 @@ impl DayCountConvention {
     }
 
     // 30E/365 day count factor calculation.
-    fn day_count_factor_thirty_e_365(start_date: Date, end_date: Date) -> f64 {
+    // The end of February is not adjusted if the end date is the termination date.
+    fn day_count_factor_thirty_e_365(
+        start_date: Date,
+        end_date: Date,
+        context: &DayCountContext,
+    ) -> f64 {
         let (y1, m1, mut d1) = Self::thirty_360_unpack_date(start_date);
         let (y2, m2, mut d2) = Self::thirty_360_unpack_date(end_date);
 
+        let is_termination_date = context.termination_date == Some(end_date);
+
         if d1 == 31 || is_last_day_of_february(start_date) {
             d1 = 30;
         }
 
-        if d2 == 31 || is_last_day_of_february(end_date) {
+        if d2 == 31 || (is_last_day_of_february(end_date) && !is_termination_date) {
             d2 = 30;
         }
 
@@ -562,21 +794,64 @@ impl DayCountConvention {
     }
 
     // 30U/360 day count factor calculation.
-    fn day_count_factor_thirty_u_360(start_date: Date, end_date: Date) -> f64 {
+    // The end of February rules only apply under the end-of-month rule.
+    fn day_count_factor_thirty_u_360(
+        start_date: Date,
+        end_date: Date,
+        context: &DayCountContext,
+    ) -> f64 {
         let (y1, m1, mut d1) = Self::thirty_360_unpack_date(start_date);
         let (y2, m2, mut d2) = Self::thirty_360_unpack_date(end_date);
 
-        if d1 == 31 || is_last_day_of_february(start_date) {
+        let start_is_february_end = context.end_of_month && is_last_day_of_february(start_date);
+        let end_is_february_end = context.end_of_month && is_last_day_of_february(end_date);
+
+        if start_is_february_end && end_is_february_end {
+            d2 = 30;
+        }
+
+        if d1 == 31 || start_is_february_end {
             d1 = 30;
         }
 
-        if d2 == 31 && d1 == 30 || is_last_day_of_february(end_date) {
+        if d2 == 31 && d1 == 30 {
             d2 = 30;
         }
 
         Self::thirty_360_numerator(y1, y2, m1, m2, d1, d2) / 360.0
     }
 
+    /// Function to shift a date by a number of years.
+    /// The 29th February is moved to the 28th February in non-leap years.
+    fn shift_years(date: Date, years: i32) -> Date {
+        let year = date.year() + years;
+        let day = date.day().min(date.month().length(year));
+
+        Date::from_calendar_date(year, date.month(), day).unwrap()
+    }
+
+    // Business/252 day count factor calculation.
+    fn day_count_factor_business_252<F>(start_date: Date, end_date: Date, is_business_day: F) -> f64
+    where
+        F: Fn(Date) -> bool,
+    {
+        if end_date < start_date {
+            return -Self::day_count_factor_business_252(end_date, start_date, is_business_day);
+        }
+
+        let mut business_days = 0;
+        let mut temp_date = start_date;
+
+        while temp_date < end_date {
+            if is_business_day(temp_date) {
+                business_days += 1;
+            }
+            temp_date += Duration::days(1);
+        }
+
+        business_days as f64 / 252.0
+    }
+
     /// Function to comput the 30/360 numerator.
     fn thirty_360_numerator(y1: i32, y2: i32, m1: i32, m2: i32, d1: i32, d2: i32) -> f64 {
         (360 * (y2 - y1) + 30 * (m2 - m1) + (d2 - d1)) as f64
@@ -587,3 +862,246 @@ impl DayCountConvention {
         (date.year(), date.month() as i32, date.day() as i32)
     }
 }
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_day_counting {
+    use super::*;
+    use crate::countries::south_america::brazil::BrazilCalendar;
+    use time::macros::date;
+
+    use DayCountConvention::*;
+
+    const TOLERANCE: f64 = 1e-12;
+
+    // Reference periods:
+    //  - The Act/Act examples from the ISDA memo "EMU and market conventions:
+    //    recent developments" (also used in the QuantLib test suite).
+    //  - End of month and end of February periods for the 30/360 family.
+    //  - The AFB example from the FBF master agreement (10/02/1994 - 30/06/1997).
+    #[rustfmt::skip]
+    const REFERENCE_PERIODS: [(Date, Date); 10] = [
+        (date!(2003 - 11 - 01), date!(2004 - 05 - 01)),
+        (date!(1999 - 02 - 01), date!(1999 - 07 - 01)),
+        (date!(2002 - 08 - 15), date!(2003 - 07 - 15)),
+        (date!(2000 - 01 - 30), date!(2000 - 06 - 30)),
+        (date!(1999 - 11 - 30), date!(2000 - 04 - 30)),
+        (date!(1994 - 02 - 10), date!(1997 - 06 - 30)),
+        (date!(2007 - 01 - 31), date!(2007 - 02 - 28)),
+        (date!(2007 - 02 - 28), date!(2008 - 02 - 29)),
+        (date!(2008 - 02 - 29), date!(2009 - 02 - 28)),
+        (date!(2007 - 08 - 31), date!(2008 - 02 - 29)),
+    ];
+
+    // Expected day count factors for each reference period above.
+    // Business/252 needs a calendar, so is tested separately.
+    #[rustfmt::skip]
+    fn reference_values() -> Vec<(DayCountConvention, [f64; 10])> {
+        vec![
+            (One_One,            [1.0; 10]),
+            (Actual_360,         [182.0 / 360.0, 150.0 / 360.0, 334.0 / 360.0, 152.0 / 360.0, 152.0 / 360.0, 1236.0 / 360.0, 28.0 / 360.0, 366.0 / 360.0, 365.0 / 360.0, 182.0 / 360.0]),
+            (Actual_364,         [182.0 / 364.0, 150.0 / 364.0, 334.0 / 364.0, 152.0 / 364.0, 152.0 / 364.0, 1236.0 / 364.0, 28.0 / 364.0, 366.0 / 364.0, 365.0 / 364.0, 182.0 / 364.0]),
+            (Actual_365_25,      [182.0 / 365.25, 150.0 / 365.25, 334.0 / 365.25, 152.0 / 365.25, 152.0 / 365.25, 1236.0 / 365.25, 28.0 / 365.25, 366.0 / 365.25, 365.0 / 365.25, 182.0 / 365.25]),
+            (Actual_365_Actual,  [0.497_267_759_562_841_5, 0.410_958_904_109_589, 0.915_068_493_150_685, 0.415_300_546_448_087_45, 0.415_300_546_448_087_45, 3.377_049_180_327_869, 0.076_712_328_767_123_29, 1.0, 1.0, 0.497_267_759_562_841_5]),
+            (Actual_365_Fixed,   [182.0 / 365.0, 150.0 / 365.0, 334.0 / 365.0, 152.0 / 365.0, 152.0 / 365.0, 1236.0 / 365.0, 28.0 / 365.0, 366.0 / 365.0, 1.0, 182.0 / 365.0]),
+            (Actual_365_Leap,    [0.497_267_759_562_841_5, 0.410_958_904_109_589, 0.915_068_493_150_685, 0.415_300_546_448_087_45, 0.415_300_546_448_087_45, 3.386_301_369_863_013_6, 0.076_712_328_767_123_29, 1.0, 1.0, 0.497_267_759_562_841_5]),
+            (Actual_Actual_AFB,  [0.497_267_759_562_841_5, 0.410_958_904_109_589, 0.915_068_493_150_685, 0.415_300_546_448_087_45, 0.415_300_546_448_087_45, 3.383_561_643_835_616, 0.076_712_328_767_123_29, 1.0, 1.0, 0.498_630_136_986_301_4]),
+            (Actual_Actual_ISDA, [0.497_724_380_567_407_75, 0.410_958_904_109_589, 0.915_068_493_150_685, 0.415_300_546_448_087_45, 0.415_540_085_335_728_7, 3.383_561_643_835_616, 0.076_712_328_767_123_29, 1.002_298_076_203_308_7, 0.997_701_923_796_691_4, 0.498_188_487_162_212_76]),
+            (NL_360,             [181.0 / 360.0, 150.0 / 360.0, 334.0 / 360.0, 151.0 / 360.0, 151.0 / 360.0, 1235.0 / 360.0, 28.0 / 360.0, 365.0 / 360.0, 365.0 / 360.0, 181.0 / 360.0]),
+            (NL_365,             [181.0 / 365.0, 150.0 / 365.0, 334.0 / 365.0, 151.0 / 365.0, 151.0 / 365.0, 1235.0 / 365.0, 28.0 / 365.0, 1.0, 1.0, 181.0 / 365.0]),
+            (Thirty_360_ISDA,    [0.5, 150.0 / 360.0, 330.0 / 360.0, 150.0 / 360.0, 150.0 / 360.0, 1220.0 / 360.0, 28.0 / 360.0, 361.0 / 360.0, 359.0 / 360.0, 179.0 / 360.0]),
+            (Thirty_E_360,       [0.5, 150.0 / 360.0, 330.0 / 360.0, 150.0 / 360.0, 150.0 / 360.0, 1220.0 / 360.0, 28.0 / 360.0, 361.0 / 360.0, 359.0 / 360.0, 179.0 / 360.0]),
+            (Thirty_E_360_ISDA,  [0.5, 150.0 / 360.0, 330.0 / 360.0, 150.0 / 360.0, 150.0 / 360.0, 1220.0 / 360.0, 30.0 / 360.0, 1.0, 1.0, 0.5]),
+            (Thirty_E_365,       [180.0 / 365.0, 150.0 / 365.0, 330.0 / 365.0, 150.0 / 365.0, 150.0 / 365.0, 1220.0 / 365.0, 30.0 / 365.0, 360.0 / 365.0, 360.0 / 365.0, 180.0 / 365.0]),
+            (Thirty_E_Plus_360,  [0.5, 150.0 / 360.0, 330.0 / 360.0, 150.0 / 360.0, 150.0 / 360.0, 1220.0 / 360.0, 28.0 / 360.0, 361.0 / 360.0, 359.0 / 360.0, 179.0 / 360.0]),
+            (Thirty_U_360,       [0.5, 150.0 / 360.0, 330.0 / 360.0, 150.0 / 360.0, 150.0 / 360.0, 1220.0 / 360.0, 28.0 / 360.0, 1.0, 1.0, 179.0 / 360.0]),
+        ]
+    }
+
+    #[test]
+    fn test_reference_values() {
+        for (convention, expected) in reference_values() {
+            for ((start, end), expected) in REFERENCE_PERIODS.iter().zip(expected) {
+                let actual = convention.day_count_factor(*start, *end);
+
+                assert!(
+                    (actual - expected).abs() < TOLERANCE,
+                    "{convention}: {start} -> {end}: expected {expected}, got {actual}"
+                );
+            }
+        }
+    }
+
+    // The ISDA memo's Act/Act ICMA example for a regular semi-annual period.
+    #[test]
+    fn test_actual_actual_icma_regular_period() {
+        let dcf = Actual_Actual_ICMA.day_count_factor(date!(2003 - 11 - 01), date!(2004 - 05 - 01));
+
+        assert!((dcf - 0.5).abs() < TOLERANCE);
+    }
+
+    // The ISDA memo's Act/Act ICMA examples that need the coupon schedule.
+    #[test]
+    fn test_actual_actual_icma_with_context() {
+        let cases = [
+            // Short first period, annual coupons.
+            (
+                date!(1999 - 02 - 01),
+                date!(1999 - 07 - 01),
+                date!(2000 - 07 - 01),
+                Frequency::Annually,
+                150.0 / 365.0,
+            ),
+            // Short first period over a year end, annual coupons.
+            (
+                date!(2002 - 08 - 15),
+                date!(2003 - 07 - 15),
+                date!(2004 - 07 - 15),
+                Frequency::Annually,
+                334.0 / 365.0,
+            ),
+            // Short final period, semi-annual coupons.
+            (
+                date!(2000 - 01 - 30),
+                date!(2000 - 06 - 30),
+                date!(2000 - 07 - 30),
+                Frequency::SemiAnnually,
+                152.0 / 364.0,
+            ),
+            // Period spanning two coupon periods, semi-annual coupons.
+            (
+                date!(2003 - 08 - 15),
+                date!(2004 - 05 - 15),
+                date!(2005 - 02 - 15),
+                Frequency::SemiAnnually,
+                0.5 + 90.0 / 364.0,
+            ),
+        ];
+
+        for (start, end, termination_date, frequency, expected) in cases {
+            let context = DayCountContext {
+                termination_date: Some(termination_date),
+                frequency: Some(frequency),
+                ..Default::default()
+            };
+
+            let dcf = Actual_Actual_ICMA.day_count_factor_with_context(start, end, &context);
+
+            assert!(
+                (dcf - expected).abs() < TOLERANCE,
+                "{start} -> {end}: expected {expected}, got {dcf}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_thirty_e_360_isda_termination_date() {
+        let (start, end) = (date!(2007 - 08 - 31), date!(2008 - 02 - 29));
+        let context = DayCountContext {
+            termination_date: Some(end),
+            ..Default::default()
+        };
+
+        assert_eq!(Thirty_E_360_ISDA.day_count_factor(start, end), 0.5);
+        assert_eq!(
+            Thirty_E_360_ISDA.day_count_factor_with_context(start, end, &context),
+            179.0 / 360.0
+        );
+        assert_eq!(
+            Thirty_E_365.day_count_factor_with_context(start, end, &context),
+            179.0 / 365.0
+        );
+    }
+
+    #[test]
+    fn test_thirty_u_360_end_of_month() {
+        let (start, end) = (date!(2007 - 02 - 28), date!(2008 - 02 - 29));
+        let context = DayCountContext {
+            end_of_month: false,
+            ..Default::default()
+        };
+
+        assert_eq!(Thirty_U_360.day_count_factor(start, end), 1.0);
+        assert_eq!(
+            Thirty_U_360.day_count_factor_with_context(start, end, &context),
+            361.0 / 360.0
+        );
+    }
+
+    #[test]
+    fn test_actual_365_leap_annual_frequency() {
+        let (start, end) = (date!(2008 - 03 - 01), date!(2008 - 12 - 31));
+        let context = DayCountContext {
+            frequency: Some(Frequency::Annually),
+            ..Default::default()
+        };
+
+        assert_eq!(Actual_365_Leap.day_count_factor(start, end), 305.0 / 366.0);
+        assert_eq!(
+            Actual_365_Leap.day_count_factor_with_context(start, end, &context),
+            305.0 / 365.0
+        );
+    }
+
+    #[test]
+    fn test_actual_actual_afb_leap_day_end() {
+        // Previously panicked when the end date was the 29th February.
+        let dcf = Actual_Actual_AFB.day_count_factor(date!(2004 - 02 - 28), date!(2008 - 02 - 29));
+
+        assert!((dcf - (4.0 + 1.0 / 365.0)).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn test_actual_actual_afb_leap_day_start() {
+        // The 28th February of a leap year is moved to the 29th when counting back.
+        let dcf = Actual_Actual_AFB.day_count_factor(date!(2008 - 02 - 29), date!(2009 - 02 - 28));
+
+        assert!((dcf - 1.0).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn test_actual_actual_isda_multiple_years() {
+        let dcf = Actual_Actual_ISDA.day_count_factor(date!(2003 - 11 - 01), date!(2006 - 05 - 01));
+
+        assert!((dcf - (61.0 / 365.0 + 2.0 + 120.0 / 365.0)).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn test_business_252_uses_calendar() {
+        let calendar = BrazilCalendar;
+        let convention = DayCountConvention::Business_252;
+
+        // January 2024 has 23 weekdays, and New Year's Day is a holiday.
+        assert_eq!(
+            calendar.day_count_factor(date!(2024 - 01 - 01), date!(2024 - 02 - 01), &convention),
+            22.0 / 252.0
+        );
+
+        // Carnival (12th-13th February 2024) is excluded.
+        assert_eq!(
+            calendar.day_count_factor(date!(2024 - 02 - 09), date!(2024 - 02 - 16), &convention),
+            3.0 / 252.0
+        );
+    }
+
+    // Without a calendar the business days cannot be counted.
+    #[test]
+    #[should_panic(expected = "Business/252 needs a calendar")]
+    fn test_business_252_without_calendar() {
+        Business_252.day_count_factor(date!(2024 - 02 - 09), date!(2024 - 02 - 16));
+    }
+
+    #[test]
+    fn test_business_252_reversed_dates() {
+        let calendar = BrazilCalendar;
+        let convention = DayCountConvention::Business_252;
+
+        assert_eq!(
+            calendar.day_count_factor(date!(2024 - 02 - 16), date!(2024 - 02 - 09), &convention),
+            -3.0 / 252.0
+        );
+    }
+}
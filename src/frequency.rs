@@ -9,99 +9,629 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use time::{Date, Duration};
+use std::fmt;
+use time::Date;
 
+use crate::calendar::Calendar;
 use crate::constants::{
-    ANNUALLY, BI_WEEKLY, DAILY, MONTHLY, QUARTERLY, SEMI_ANNUALLY, SEMI_MONTHLY, SEMI_QUARTERLY,
-    TRI_ANNUALLY, WEEKLY,
+    ANNUALLY, BI_MONTHLY, BI_WEEKLY, BUSINESS_DAILY, DAILY, EVERY_FOUR_WEEKS, MONTHLY, ONCE,
+    QUARTERLY, SEMI_ANNUALLY, SEMI_MONTHLY, SEMI_QUARTERLY, TRI_ANNUALLY, WEEKLY,
 };
+use crate::period::{Period, TimeUnit};
 
 /// Interest/coupon frequency per year.
 /// This is important in finance, as it determines the number of times
 /// a cash flow is paid in a year, and thus affects the present value
 /// of the cash flows.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Frequency {
-    /// Daily (252 per year).
-    Daily = DAILY,
+    /// Once (0 per year), such as a zero-coupon bond paying only at maturity.
+    Once,
+
+    /// Daily, every calendar day (365 per year, or 366 in a leap year).
+    /// Use this for daily compounding on calendar day conventions (e.g. Act/365).
+    Daily,
+
+    /// Business daily, every business day (252 per year by convention).
+    /// The actual number of business days depends on the calendar.
+    BusinessDaily,
 
     /// Weekly (52 per year).
-    Weekly = WEEKLY,
+    Weekly,
 
     /// Bi-weekly (26 per year).
-    BiWeekly = BI_WEEKLY,
+    BiWeekly,
+
+    /// Every four weeks (13 per year).
+    EveryFourWeeks,
 
     /// Semi-monthly (24 per year).
-    SemiMonthly = SEMI_MONTHLY,
+    SemiMonthly,
 
     /// Monthly (12 per year).
-    Monthly = MONTHLY,
+    Monthly,
 
     /// Semi-quarterly (8 per year).
-    SemiQuarterly = SEMI_QUARTERLY,
+    SemiQuarterly,
+
+    /// Bi-monthly, every two months (6 per year).
+    BiMonthly,
 
-    /// Quarterly.
-    Quarterly = QUARTERLY,
+    /// Quarterly (4 per year).
+    Quarterly,
 
-    /// Tri-annually.
-    TriAnnually = TRI_ANNUALLY,
+    /// Tri-annually, every four months (3 per year).
+    TriAnnually,
+
+    /// Semi-annually (2 per year).
+    SemiAnnually,
+
+    /// Annually (1 per year).
+    Annually,
+
+    /// Any other frequency, given by the period between payments.
+    Other(Period),
+}
 
-    /// Semi-annually.
-    SemiAnnually = SEMI_ANNUALLY,
+/// Error returned when a `Frequency` cannot be inferred from a sequence of dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InferFrequencyError {
+    /// Fewer than two distinct dates were given.
+    NotEnoughDates,
 
-    /// Annually.
-    Annually = ANNUALLY,
+    /// The periods between the dates do not match any single frequency.
+    Irregular,
 }
 
 impl Frequency {
-    /// Function to infer the frequency between two `Date`s.
+    /// Function to infer the frequency of a sequence of `Date`s,
+    /// such as a list of cash flow dates.
     ///
-    /// This is a very simple (fallible) way to infer the frequency between two dates.
+    /// Each period between consecutive dates is matched to the nearest
+    /// frequency, allowing for dates that were rolled to a business day
+    /// and for the varying length of months. The most common frequency is
+    /// returned, as long as it matches the majority of the periods.
+    /// So an irregular first or last (stub) period is tolerated.
     ///
-    /// # Panics
+    /// Daily dates are `Daily` if every calendar day is present,
+    /// and `BusinessDaily` if some (e.g. weekends) are skipped.
+    /// Dates four weeks apart are `EveryFourWeeks` if most of the periods
+    /// are exactly 28 days (which a monthly schedule has at most once a year),
+    /// and `Monthly` otherwise.
+    /// Dates half a month apart are `SemiMonthly` if there are two of them in
+    /// every month (such as the 1st and the 15th), and `BiWeekly` otherwise.
     ///
-    /// Panics if the difference between the two dates is not a recognized frequency.
-    pub fn infer_frequency(start: Date, end: Date) -> Frequency {
-        let diff = end - start;
-
-        if diff == Duration::days(1) {
-            Frequency::Daily
-        } else if diff == Duration::weeks(1) {
-            Frequency::Weekly
-        } else if diff == Duration::weeks(2) {
-            Frequency::BiWeekly
-        } else if diff > Duration::days(14) && diff < Duration::days(16) {
-            Frequency::SemiMonthly
-        } else if diff >= Duration::days(28) && diff <= Duration::days(31) {
-            Frequency::Monthly
-        } else if diff >= Duration::days(45) && diff <= Duration::days(46) {
-            Frequency::SemiQuarterly
-        } else if diff >= Duration::days(91) && diff <= Duration::days(92) {
-            Frequency::Quarterly
-        } else if diff >= Duration::days(121) && diff <= Duration::days(122) {
-            Frequency::TriAnnually
-        } else if diff >= Duration::days(182) && diff <= Duration::days(183) {
-            Frequency::SemiAnnually
-        } else if diff >= Duration::days(365) && diff <= Duration::days(366) {
-            Frequency::Annually
-        } else {
-            panic!("Unable to infer frequency between the two dates.")
+    /// The dates do not need to be sorted.
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::frequency::Frequency;
+    ///
+    /// // Quarterly dates, with the 1st of July 2023 (Saturday) rolled to the Monday.
+    /// let dates = [
+    ///     date!(2023 - 01 - 01),
+    ///     date!(2023 - 04 - 01),
+    ///     date!(2023 - 07 - 03),
+    ///     date!(2023 - 10 - 01),
+    /// ];
+    ///
+    /// assert_eq!(Frequency::infer_frequency(&dates), Ok(Frequency::Quarterly));
+    /// ```
+    pub fn infer_frequency(dates: &[Date]) -> Result<Frequency, InferFrequencyError> {
+        let mut dates = dates.to_vec();
+        dates.sort();
+        dates.dedup();
+
+        if dates.len() < 2 {
+            return Err(InferFrequencyError::NotEnoughDates);
+        }
+
+        if Self::is_semi_monthly(&dates) {
+            return Ok(Frequency::SemiMonthly);
+        }
+
+        let periods = dates.len() - 1;
+        let mut counts = vec![0_usize; INFERABLE_FREQUENCIES.len()];
+
+        for window in dates.windows(2) {
+            let days = (window[1] - window[0]).whole_days() as f64;
+
+            if let Some(index) = Self::nearest_frequency(days) {
+                counts[index] += 1;
+            }
+        }
+
+        let (index, &count) = counts
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, &count)| count)
+            .unwrap();
+
+        if 2 * count <= periods {
+            return Err(InferFrequencyError::Irregular);
+        }
+
+        match INFERABLE_FREQUENCIES[index].0 {
+            Frequency::BusinessDaily
+                if dates
+                    .windows(2)
+                    .all(|window| (window[1] - window[0]).whole_days() == 1) =>
+            {
+                Ok(Frequency::Daily)
+            }
+            Frequency::Monthly if Self::is_every_four_weeks(&dates) => {
+                Ok(Frequency::EveryFourWeeks)
+            }
+            frequency => Ok(frequency),
+        }
+    }
+
+    /// Function to check if most of the periods between (sorted) dates are
+    /// exactly four weeks, as a monthly schedule only is around February.
+    fn is_every_four_weeks(dates: &[Date]) -> bool {
+        let periods = dates.len() - 1;
+        let four_weeks = dates
+            .windows(2)
+            .filter(|window| (window[1] - window[0]).whole_days() == 28)
+            .count();
+
+        four_weeks >= 2 && 2 * four_weeks > periods
+    }
+
+    /// Function to check if (sorted) dates about half a month apart are two in
+    /// every month, and not all two weeks apart, as a bi-weekly schedule has
+    /// three dates in some months.
+    fn is_semi_monthly(dates: &[Date]) -> bool {
+        let days = || {
+            dates
+                .windows(2)
+                .map(|window| (window[1] - window[0]).whole_days())
+        };
+
+        if !days().all(|days| (11..=19).contains(&days)) || days().all(|days| days <= 14) {
+            return false;
         }
+
+        // the first and last months may only be partly covered
+        let dates_in_months: Vec<usize> = dates
+            .chunk_by(|a, b| (a.year(), a.month()) == (b.year(), b.month()))
+            .map(<[Date]>::len)
+            .collect();
+
+        dates_in_months.iter().all(|&count| count <= 2)
+            && dates_in_months[1..dates_in_months.len().saturating_sub(1)]
+                .iter()
+                .all(|&count| count == 2)
+    }
+
+    /// Function to find the index of the inferable frequency nearest to a
+    /// period of the given number of days, if it is within tolerance.
+    fn nearest_frequency(days: f64) -> Option<usize> {
+        INFERABLE_FREQUENCIES
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, nominal_days, tolerance))| (days - nominal_days).abs() <= *tolerance)
+            .min_by(|(_, (_, a, _)), (_, (_, b, _))| (days - a).abs().total_cmp(&(days - b).abs()))
+            .map(|(index, _)| index)
     }
 
-    /// Get the number of times the frequency occurs in a year.
+    /// Get the (nominal) number of times the frequency occurs in a year.
+    ///
+    /// For `Daily` this is 365, and for `BusinessDaily` this is 252.
+    /// Use `periods_between` for the actual number of occurrences.
+    ///
+    /// For `Other` frequencies, this is rounded down to a whole number
+    /// (and is zero for periods longer than a year).
     pub fn times_in_year(&self) -> isize {
         match self {
+            Frequency::Once => ONCE,
             Frequency::Daily => DAILY,
+            Frequency::BusinessDaily => BUSINESS_DAILY,
             Frequency::Weekly => WEEKLY,
             Frequency::BiWeekly => BI_WEEKLY,
+            Frequency::EveryFourWeeks => EVERY_FOUR_WEEKS,
             Frequency::SemiMonthly => SEMI_MONTHLY,
             Frequency::Monthly => MONTHLY,
             Frequency::SemiQuarterly => SEMI_QUARTERLY,
+            Frequency::BiMonthly => BI_MONTHLY,
             Frequency::Quarterly => QUARTERLY,
             Frequency::TriAnnually => TRI_ANNUALLY,
             Frequency::SemiAnnually => SEMI_ANNUALLY,
             Frequency::Annually => ANNUALLY,
+            Frequency::Other(period) => {
+                let units_in_year = match period.unit {
+                    TimeUnit::Days => 365,
+                    TimeUnit::Weeks => WEEKLY,
+                    TimeUnit::Months => MONTHLY,
+                    TimeUnit::Years => ANNUALLY,
+                };
+
+                units_in_year
+                    .checked_div(period.length as isize)
+                    .unwrap_or(0)
+            }
+        }
+    }
+
+    /// Get the tenor (period between payments) of the frequency.
+    ///
+    /// Returns `None` for `Once`, for `BusinessDaily` which depends on the calendar,
+    /// and for `SemiMonthly` and `SemiQuarterly` which are not a whole number
+    /// of days, weeks or months.
+    ///
+    /// ```
+    /// use calenda_rs::frequency::Frequency;
+    /// use calenda_rs::period::{Period, TimeUnit};
+    ///
+    /// assert_eq!(Frequency::Monthly.tenor(), Some(Period::new(1, TimeUnit::Months)));
+    /// assert_eq!(Frequency::Once.tenor(), None);
+    /// ```
+    #[rustfmt::skip]
+    pub fn tenor(&self) -> Option<Period> {
+        match self {
+            Frequency::Once             => None,
+            Frequency::Daily            => Some(Period::new(1, TimeUnit::Days)),
+            Frequency::BusinessDaily    => None,
+            Frequency::Weekly           => Some(Period::new(1, TimeUnit::Weeks)),
+            Frequency::BiWeekly         => Some(Period::new(2, TimeUnit::Weeks)),
+            Frequency::EveryFourWeeks   => Some(Period::new(4, TimeUnit::Weeks)),
+            Frequency::SemiMonthly      => None,
+            Frequency::Monthly          => Some(Period::new(1, TimeUnit::Months)),
+            Frequency::SemiQuarterly    => None,
+            Frequency::BiMonthly        => Some(Period::new(2, TimeUnit::Months)),
+            Frequency::Quarterly        => Some(Period::new(3, TimeUnit::Months)),
+            Frequency::TriAnnually      => Some(Period::new(4, TimeUnit::Months)),
+            Frequency::SemiAnnually     => Some(Period::new(6, TimeUnit::Months)),
+            Frequency::Annually         => Some(Period::new(1, TimeUnit::Years)),
+            Frequency::Other(period)    => Some(*period),
+        }
+    }
+
+    /// Count the actual number of occurrences of the frequency from `start`
+    /// (exclusive) to `end` (inclusive), such as the number of compounding periods.
+    ///
+    /// Unlike `times_in_year`, this accounts for leap years, and for the
+    /// holidays of the calendar when the frequency is `BusinessDaily`.
+    /// `SemiMonthly` and `SemiQuarterly` occur at the start and middle of each
+    /// month and quarter (measured from `start`) respectively.
+    /// `Once` occurs once, at the end.
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::frequency::Frequency;
+    /// use calenda_rs::countries::europe::united_kingdom::UnitedKingdomCalendar;
+    ///
+    /// let calendar = UnitedKingdomCalendar;
+    /// let (start, end) = (date!(2024 - 01 - 01), date!(2025 - 01 - 01));
+    ///
+    /// assert_eq!(Frequency::Daily.periods_between(start, end, &calendar), 366);
+    /// assert_eq!(Frequency::BusinessDaily.periods_between(start, end, &calendar), 254);
+    /// assert_eq!(Frequency::Monthly.periods_between(start, end, &calendar), 12);
+    /// ```
+    pub fn periods_between<C: Calendar>(&self, start: Date, end: Date, calendar: &C) -> i64 {
+        if end <= start {
+            return 0;
+        }
+
+        match self {
+            Frequency::Once => 1,
+            Frequency::BusinessDaily => {
+                let mut count = 0;
+                let mut date = start;
+
+                while date < end {
+                    date = date.next_day().unwrap();
+
+                    if calendar.is_business_day(date) {
+                        count += 1;
+                    }
+                }
+
+                count
+            }
+            Frequency::SemiMonthly => Self::half_periods_between(start, end, 1),
+            Frequency::SemiQuarterly => Self::half_periods_between(start, end, 3),
+            _ => {
+                let tenor = self.tenor().unwrap();
+
+                if tenor.length <= 0 {
+                    return 0;
+                }
+
+                let mut count = 0;
+
+                while tenor.advance(start, count as i32 + 1) <= end {
+                    count += 1;
+                }
+
+                count
+            }
+        }
+    }
+
+    /// Function to count the occurrences of a frequency that is half of a
+    /// given number of months, from `start` (exclusive) to `end` (inclusive).
+    fn half_periods_between(start: Date, end: Date, months: i32) -> i64 {
+        let tenor = Period::new(months, TimeUnit::Months);
+        let mut count = 0;
+
+        loop {
+            let period_start = tenor.advance(start, count);
+            let period_end = tenor.advance(start, count + 1);
+            let midpoint = period_start + (period_end - period_start) / 2;
+
+            if period_end <= end {
+                count += 1;
+            } else {
+                return 2 * count as i64 + i64::from(midpoint <= end);
+            }
         }
     }
 }
+
+/// Frequencies that can be inferred from a sequence of dates, with their
+/// nominal period length in days, and the tolerance (in days) for a period
+/// to match it. The tolerance allows for dates rolled over a long weekend,
+/// and for the varying length of months.
+///
+/// `EveryFourWeeks` is not listed, since its periods are also a month within
+/// tolerance: it is told apart from `Monthly` afterwards.
+#[rustfmt::skip]
+const INFERABLE_FREQUENCIES: [(Frequency, f64, f64); 11] = [
+    (Frequency::BusinessDaily,   1.0,            3.0),
+    (Frequency::Weekly,          7.0,            3.0),
+    (Frequency::BiWeekly,        14.0,           3.0),
+    (Frequency::SemiMonthly,     365.25 / 24.0,  4.0),
+    (Frequency::Monthly,         365.25 / 12.0,  5.0),
+    (Frequency::SemiQuarterly,   365.25 / 8.0,   6.0),
+    (Frequency::BiMonthly,       365.25 / 6.0,   6.0),
+    (Frequency::Quarterly,       365.25 / 4.0,   7.0),
+    (Frequency::TriAnnually,     365.25 / 3.0,   8.0),
+    (Frequency::SemiAnnually,    365.25 / 2.0,   9.0),
+    (Frequency::Annually,        365.25,         10.0),
+];
+
+impl fmt::Display for InferFrequencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotEnoughDates => write!(f, "At least two distinct dates are required."),
+            Self::Irregular => write!(f, "The dates do not have a regular frequency."),
+        }
+    }
+}
+
+impl std::error::Error for InferFrequencyError {}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_frequency {
+    use super::*;
+    use crate::countries::north_america::united_states::UnitedStatesCalendar;
+    use time::macros::date;
+
+    #[test]
+    fn test_infer_monthly_with_rolled_dates() {
+        // Month ends, rolled to the previous business day when on a weekend.
+        let dates = [
+            date!(2023 - 01 - 31),
+            date!(2023 - 02 - 28),
+            date!(2023 - 03 - 31),
+            date!(2023 - 04 - 28),
+            date!(2023 - 05 - 31),
+            date!(2023 - 06 - 30),
+            date!(2023 - 07 - 31),
+            date!(2023 - 08 - 31),
+            date!(2023 - 09 - 29),
+        ];
+
+        assert_eq!(Frequency::infer_frequency(&dates), Ok(Frequency::Monthly));
+    }
+
+    #[test]
+    fn test_infer_monthly_over_february() {
+        let dates = [
+            date!(2024 - 01 - 15),
+            date!(2024 - 02 - 15),
+            date!(2024 - 03 - 15),
+        ];
+
+        assert_eq!(Frequency::infer_frequency(&dates), Ok(Frequency::Monthly));
+
+        let dates = [
+            date!(2023 - 02 - 01),
+            date!(2023 - 03 - 01),
+            date!(2023 - 04 - 01),
+        ];
+
+        assert_eq!(Frequency::infer_frequency(&dates), Ok(Frequency::Monthly));
+
+        // Month ends, from the 31st January to the 28th February.
+        let dates = [
+            date!(2023 - 01 - 31),
+            date!(2023 - 02 - 28),
+            date!(2023 - 03 - 31),
+        ];
+
+        assert_eq!(Frequency::infer_frequency(&dates), Ok(Frequency::Monthly));
+    }
+
+    #[test]
+    fn test_infer_every_four_weeks() {
+        let dates = [
+            date!(2024 - 01 - 15),
+            date!(2024 - 02 - 12),
+            date!(2024 - 03 - 11),
+            date!(2024 - 04 - 08),
+            date!(2024 - 05 - 06),
+        ];
+
+        assert_eq!(
+            Frequency::infer_frequency(&dates),
+            Ok(Frequency::EveryFourWeeks)
+        );
+    }
+
+    #[test]
+    fn test_infer_semi_monthly() {
+        let dates = [
+            date!(2023 - 01 - 01),
+            date!(2023 - 01 - 15),
+            date!(2023 - 02 - 01),
+            date!(2023 - 02 - 15),
+            date!(2023 - 03 - 01),
+            date!(2023 - 03 - 15),
+            date!(2023 - 04 - 01),
+            date!(2023 - 04 - 15),
+        ];
+
+        assert_eq!(
+            Frequency::infer_frequency(&dates),
+            Ok(Frequency::SemiMonthly)
+        );
+
+        // two weeks apart, with three dates in March
+        let dates = [
+            date!(2023 - 02 - 01),
+            date!(2023 - 02 - 15),
+            date!(2023 - 03 - 01),
+            date!(2023 - 03 - 15),
+            date!(2023 - 03 - 29),
+            date!(2023 - 04 - 12),
+        ];
+
+        assert_eq!(Frequency::infer_frequency(&dates), Ok(Frequency::BiWeekly));
+    }
+
+    #[test]
+    fn test_infer_with_stub_period() {
+        // Semi-annual dates with a short first period.
+        let dates = [
+            date!(2023 - 03 - 15),
+            date!(2023 - 06 - 15),
+            date!(2023 - 12 - 15),
+            date!(2024 - 06 - 17),
+            date!(2024 - 12 - 16),
+        ];
+
+        assert_eq!(
+            Frequency::infer_frequency(&dates),
+            Ok(Frequency::SemiAnnually)
+        );
+    }
+
+    #[test]
+    fn test_infer_business_daily() {
+        let dates = [
+            date!(2023 - 08 - 24),
+            date!(2023 - 08 - 25),
+            date!(2023 - 08 - 28),
+            date!(2023 - 08 - 29),
+        ];
+
+        assert_eq!(
+            Frequency::infer_frequency(&dates),
+            Ok(Frequency::BusinessDaily)
+        );
+    }
+
+    #[test]
+    fn test_infer_calendar_daily() {
+        let dates = [
+            date!(2023 - 08 - 25),
+            date!(2023 - 08 - 26),
+            date!(2023 - 08 - 27),
+            date!(2023 - 08 - 28),
+        ];
+
+        assert_eq!(Frequency::infer_frequency(&dates), Ok(Frequency::Daily));
+    }
+
+    #[test]
+    fn test_periods_between() {
+        let calendar = UnitedStatesCalendar;
+        let (start, end) = (date!(2023 - 01 - 01), date!(2024 - 01 - 01));
+
+        assert_eq!(Frequency::Daily.periods_between(start, end, &calendar), 365);
+        // 260 weekdays, less 11 holidays falling on weekdays.
+        assert_eq!(
+            Frequency::BusinessDaily.periods_between(start, end, &calendar),
+            249
+        );
+        assert_eq!(Frequency::Weekly.periods_between(start, end, &calendar), 52);
+        assert_eq!(
+            Frequency::SemiMonthly.periods_between(start, end, &calendar),
+            24
+        );
+        assert_eq!(
+            Frequency::SemiQuarterly.periods_between(start, end, &calendar),
+            8
+        );
+        assert_eq!(
+            Frequency::Quarterly.periods_between(start, end, &calendar),
+            4
+        );
+        assert_eq!(Frequency::Once.periods_between(start, end, &calendar), 1);
+        assert_eq!(
+            Frequency::Annually.periods_between(end, start, &calendar),
+            0
+        );
+
+        // Part way through a period.
+        let end = date!(2023 - 05 - 20);
+
+        assert_eq!(
+            Frequency::SemiMonthly.periods_between(start, end, &calendar),
+            9
+        );
+        assert_eq!(
+            Frequency::SemiQuarterly.periods_between(start, end, &calendar),
+            3
+        );
+    }
+
+    #[test]
+    fn test_infer_errors() {
+        assert_eq!(
+            Frequency::infer_frequency(&[date!(2023 - 01 - 01)]),
+            Err(InferFrequencyError::NotEnoughDates)
+        );
+        assert_eq!(
+            Frequency::infer_frequency(&[
+                date!(2023 - 01 - 01),
+                date!(2023 - 01 - 21),
+                date!(2023 - 05 - 01),
+            ]),
+            Err(InferFrequencyError::Irregular)
+        );
+    }
+
+    #[test]
+    fn test_tenor() {
+        assert_eq!(
+            Frequency::Quarterly.tenor(),
+            Some(Period::new(3, TimeUnit::Months))
+        );
+        assert_eq!(
+            Frequency::Other(Period::new(10, TimeUnit::Days)).tenor(),
+            Some(Period::new(10, TimeUnit::Days))
+        );
+        assert_eq!(Frequency::SemiMonthly.tenor(), None);
+    }
+
+    #[test]
+    fn test_times_in_year() {
+        assert_eq!(Frequency::Once.times_in_year(), 0);
+        assert_eq!(Frequency::Daily.times_in_year(), 365);
+        assert_eq!(Frequency::BusinessDaily.times_in_year(), 252);
+        assert_eq!(Frequency::SemiQuarterly.times_in_year(), 8);
+        assert_eq!(Frequency::BiMonthly.times_in_year(), 6);
+        assert_eq!(Frequency::EveryFourWeeks.times_in_year(), 13);
+        assert_eq!(
+            Frequency::Other(Period::new(2, TimeUnit::Months)).times_in_year(),
+            6
+        );
+    }
+}
@@ -0,0 +1,423 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! This module defines the (arithmetic) Hebrew calendar, and the dates of the
+//! Jewish holidays, as observed in Israel.
+//!
+//! Years are counted from the creation (AM), and start on 1 Tishri (Rosh Hashanah),
+//! in September or October. Leap years (7 in a 19 year cycle) add a month,
+//! Adar I, before Adar (Adar II). The algorithms are those of Dershowitz and Reingold,
+//! *Calendrical Calculations*.
+
+use time::{Date, Duration, Weekday};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Months of the Hebrew calendar, in the order of the (civil) year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HebrewMonth {
+    /// Tishri (September - October).
+    Tishri,
+    /// Heshvan (October - November), of 29 or 30 days.
+    Heshvan,
+    /// Kislev (November - December), of 29 or 30 days.
+    Kislev,
+    /// Tevet (December - January).
+    Tevet,
+    /// Shevat (January - February).
+    Shevat,
+    /// Adar (February - March), or Adar I in leap years.
+    Adar,
+    /// Adar II (March), in leap years only.
+    AdarII,
+    /// Nisan (March - April).
+    Nisan,
+    /// Iyar (April - May).
+    Iyar,
+    /// Sivan (May - June).
+    Sivan,
+    /// Tammuz (June - July).
+    Tammuz,
+    /// Av (July - August).
+    Av,
+    /// Elul (August - September).
+    Elul,
+}
+
+/// A date in the Hebrew calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HebrewDate {
+    /// Hebrew year (AM).
+    pub year: i32,
+
+    /// Hebrew month.
+    pub month: HebrewMonth,
+
+    /// Day of the Hebrew month (1 - 30).
+    pub day: u8,
+}
+
+/// Jewish holidays (the first day, as observed in Israel).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JewishHoliday {
+    /// Rosh Hashanah, the New Year (1 - 2 Tishri).
+    RoshHashanah,
+
+    /// Yom Kippur, the Day of Atonement (10 Tishri).
+    YomKippur,
+
+    /// Sukkot, the Feast of Tabernacles (15 Tishri).
+    Sukkot,
+
+    /// Shemini Atzeret and Simchat Torah (22 Tishri).
+    SimchatTorah,
+
+    /// Hanukkah (25 Kislev).
+    Hanukkah,
+
+    /// Purim (14 Adar, or 14 Adar II in leap years).
+    Purim,
+
+    /// Pesach, the Passover (15 Nisan).
+    Pesach,
+
+    /// Seventh day of Pesach (21 Nisan).
+    SeventhDayOfPesach,
+
+    /// Yom Ha'atzmaut, Independence Day (5 Iyar, moved to avoid the Sabbath).
+    YomHaatzmaut,
+
+    /// Shavuot, the Feast of Weeks (6 Sivan).
+    Shavuot,
+
+    /// Tisha B'Av (9 Av, postponed to Sunday if on the Sabbath).
+    TishaBav,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Julian day number of the epoch of the Hebrew calendar
+/// (1 Tishri AM 1 = 7 October 3761 BC, Julian).
+const HEBREW_EPOCH: i64 = 347_998;
+
+/// Number of parts (halakim, 1/1080 hour) in a day.
+const DAY_PARTS: i64 = 25_920;
+
+/// Difference between the Hebrew and the Gregorian years, from Tishri.
+const HEBREW_YEAR_OFFSET: i32 = 3761;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl HebrewMonth {
+    /// All months, in the order of the year.
+    const ALL: [HebrewMonth; 13] = [
+        HebrewMonth::Tishri,
+        HebrewMonth::Heshvan,
+        HebrewMonth::Kislev,
+        HebrewMonth::Tevet,
+        HebrewMonth::Shevat,
+        HebrewMonth::Adar,
+        HebrewMonth::AdarII,
+        HebrewMonth::Nisan,
+        HebrewMonth::Iyar,
+        HebrewMonth::Sivan,
+        HebrewMonth::Tammuz,
+        HebrewMonth::Av,
+        HebrewMonth::Elul,
+    ];
+
+    /// Function to get the number of days of the month in a year (`None` for Adar II in a common year).
+    pub fn days(&self, year: i32) -> Option<u8> {
+        let year_days = hebrew_year_days(year);
+
+        match self {
+            HebrewMonth::Heshvan if year_days % 10 == 5 => Some(30),
+            HebrewMonth::Kislev if year_days % 10 == 3 => Some(29),
+            HebrewMonth::Adar if is_hebrew_leap_year(year) => Some(30),
+            HebrewMonth::AdarII if !is_hebrew_leap_year(year) => None,
+            HebrewMonth::Tishri
+            | HebrewMonth::Kislev
+            | HebrewMonth::Shevat
+            | HebrewMonth::Nisan
+            | HebrewMonth::Sivan
+            | HebrewMonth::Av => Some(30),
+            _ => Some(29),
+        }
+    }
+}
+
+impl HebrewDate {
+    /// Create a new Hebrew date, if it exists in the calendar.
+    pub fn new(year: i32, month: HebrewMonth, day: u8) -> Option<Self> {
+        match (1..=month.days(year)?).contains(&day) {
+            true => Some(Self { year, month, day }),
+            false => None,
+        }
+    }
+
+    /// Convert a Gregorian date to a Hebrew date.
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::hebrew_calendar::{HebrewDate, HebrewMonth};
+    ///
+    /// let hebrew = HebrewDate::from_gregorian(date!(2024 - 04 - 23));
+    ///
+    /// assert_eq!(hebrew, HebrewDate::new(5784, HebrewMonth::Nisan, 15).unwrap());
+    /// ```
+    pub fn from_gregorian(date: Date) -> Self {
+        let jdn = date.to_julian_day() as i64;
+
+        // The Hebrew year starts in September or October
+        let mut year = date.year() + HEBREW_YEAR_OFFSET - 1;
+        while hebrew_new_year(year + 1) <= jdn {
+            year += 1;
+        }
+
+        let mut start = hebrew_new_year(year);
+
+        for month in HebrewMonth::ALL {
+            let Some(days) = month.days(year) else {
+                continue;
+            };
+
+            if jdn < start + days as i64 {
+                return Self {
+                    year,
+                    month,
+                    day: (jdn - start) as u8 + 1,
+                };
+            }
+
+            start += days as i64;
+        }
+
+        unreachable!("a Hebrew year has at most 385 days")
+    }
+
+    /// Convert the Hebrew date to a Gregorian date (`None` if the date does not exist,
+    /// or is out of range).
+    pub fn to_gregorian(&self) -> Option<Date> {
+        if !(1..=self.month.days(self.year)?).contains(&self.day) {
+            return None;
+        }
+
+        let days_before: i64 = HebrewMonth::ALL
+            .iter()
+            .take_while(|month| **month != self.month)
+            .filter_map(|month| month.days(self.year))
+            .map(i64::from)
+            .sum();
+
+        let jdn = hebrew_new_year(self.year) + days_before + self.day as i64 - 1;
+
+        Date::from_julian_day(i32::try_from(jdn).ok()?).ok()
+    }
+}
+
+impl JewishHoliday {
+    /// Function to get the (month, day) of the holiday in a Hebrew year,
+    /// before any move to avoid the Sabbath.
+    pub fn month_day(&self, hebrew_year: i32) -> (HebrewMonth, u8) {
+        match self {
+            JewishHoliday::RoshHashanah => (HebrewMonth::Tishri, 1),
+            JewishHoliday::YomKippur => (HebrewMonth::Tishri, 10),
+            JewishHoliday::Sukkot => (HebrewMonth::Tishri, 15),
+            JewishHoliday::SimchatTorah => (HebrewMonth::Tishri, 22),
+            JewishHoliday::Hanukkah => (HebrewMonth::Kislev, 25),
+            JewishHoliday::Purim if is_hebrew_leap_year(hebrew_year) => (HebrewMonth::AdarII, 14),
+            JewishHoliday::Purim => (HebrewMonth::Adar, 14),
+            JewishHoliday::Pesach => (HebrewMonth::Nisan, 15),
+            JewishHoliday::SeventhDayOfPesach => (HebrewMonth::Nisan, 21),
+            JewishHoliday::YomHaatzmaut => (HebrewMonth::Iyar, 5),
+            JewishHoliday::Shavuot => (HebrewMonth::Sivan, 6),
+            JewishHoliday::TishaBav => (HebrewMonth::Av, 9),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to check if a Hebrew year is a leap year (of 13 months).
+pub fn is_hebrew_leap_year(year: i32) -> bool {
+    (7 * year as i64 + 1).rem_euclid(19) < 7
+}
+
+/// Function to get the number of days in a Hebrew year (353 - 355, or 383 - 385 in leap years).
+pub fn hebrew_year_days(year: i32) -> i64 {
+    hebrew_new_year(year + 1) - hebrew_new_year(year)
+}
+
+/// Function to get the number of days from the epoch to the molad (mean new moon) of Tishri,
+/// postponed if it falls on a Sunday, Wednesday or Friday.
+fn hebrew_elapsed_days(year: i32) -> i64 {
+    let months = (235 * year as i64 - 234).div_euclid(19);
+    let parts = 12_084 + 13_753 * months;
+    let days = 29 * months + parts.div_euclid(DAY_PARTS);
+
+    match (3 * (days + 1)).rem_euclid(7) < 3 {
+        true => days + 1,
+        false => days,
+    }
+}
+
+/// Function to get the Julian day number of Rosh Hashanah (1 Tishri) of a Hebrew year.
+fn hebrew_new_year(year: i32) -> i64 {
+    let (previous, current, next) = (
+        hebrew_elapsed_days(year - 1),
+        hebrew_elapsed_days(year),
+        hebrew_elapsed_days(year + 1),
+    );
+
+    // Postponements keeping the year length within the allowed values
+    let delay = match (next - current, current - previous) {
+        (356, _) => 2,
+        (_, 382) => 1,
+        _ => 0,
+    };
+
+    HEBREW_EPOCH + current + delay
+}
+
+/// Function to get the date of a Jewish holiday in a Gregorian year, as observed in Israel.
+///
+/// ```
+/// use time::macros::date;
+/// use calenda_rs::hebrew_calendar::{jewish_holiday, JewishHoliday};
+///
+/// assert_eq!(jewish_holiday(JewishHoliday::RoshHashanah, 2024), Some(date!(2024 - 10 - 03)));
+/// assert_eq!(jewish_holiday(JewishHoliday::Pesach, 2025), Some(date!(2025 - 04 - 13)));
+/// ```
+pub fn jewish_holiday(holiday: JewishHoliday, year: i32) -> Option<Date> {
+    // Tishri and Kislev fall in the Hebrew year starting in the autumn
+    let hebrew_year = match holiday {
+        JewishHoliday::RoshHashanah
+        | JewishHoliday::YomKippur
+        | JewishHoliday::Sukkot
+        | JewishHoliday::SimchatTorah
+        | JewishHoliday::Hanukkah => year + HEBREW_YEAR_OFFSET,
+        _ => year + HEBREW_YEAR_OFFSET - 1,
+    };
+
+    let (month, day) = holiday.month_day(hebrew_year);
+    let date = HebrewDate::new(hebrew_year, month, day)?.to_gregorian()?;
+
+    let days = match (holiday, date.weekday()) {
+        // Brought forward to Thursday from Friday and Saturday, postponed from Monday (since 2004)
+        (JewishHoliday::YomHaatzmaut, Weekday::Friday) => -1,
+        (JewishHoliday::YomHaatzmaut, Weekday::Saturday) => -2,
+        (JewishHoliday::YomHaatzmaut, Weekday::Monday) if year >= 2004 => 1,
+        (JewishHoliday::TishaBav, Weekday::Saturday) => 1,
+        _ => 0,
+    };
+
+    Some(date + Duration::days(days))
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_hebrew_calendar {
+    use super::*;
+    use crate::utilities::date_sequence;
+    use time::macros::date;
+    use HebrewMonth::*;
+    use JewishHoliday::*;
+
+    #[test]
+    fn test_year_lengths() {
+        assert!(is_hebrew_leap_year(5784));
+        assert!(!is_hebrew_leap_year(5785));
+        assert!(is_hebrew_leap_year(5787));
+
+        assert_eq!(hebrew_year_days(5784), 383);
+        assert_eq!(hebrew_year_days(5785), 355);
+        assert_eq!(hebrew_year_days(5786), 354);
+
+        assert_eq!(Heshvan.days(5785), Some(30));
+        assert_eq!(Kislev.days(5784), Some(29));
+        assert_eq!(Adar.days(5784), Some(30));
+        assert_eq!(Adar.days(5785), Some(29));
+        assert_eq!(AdarII.days(5785), None);
+        assert_eq!(HebrewDate::new(5786, Heshvan, 30), None);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(
+            HebrewDate::new(5785, Tishri, 1).unwrap().to_gregorian(),
+            Some(date!(2024 - 10 - 03))
+        );
+        assert_eq!(
+            HebrewDate::new(5784, AdarII, 14).unwrap().to_gregorian(),
+            Some(date!(2024 - 03 - 24))
+        );
+        assert_eq!(
+            HebrewDate::from_gregorian(date!(2024 - 10 - 02)),
+            HebrewDate::new(5784, Elul, 29).unwrap()
+        );
+
+        // Round trip over a few years
+        for date in date_sequence(date!(2020 - 01 - 01), date!(2030 - 12 - 31)) {
+            assert_eq!(HebrewDate::from_gregorian(date).to_gregorian(), Some(date));
+        }
+    }
+
+    #[test]
+    fn test_holidays() {
+        assert_eq!(
+            jewish_holiday(RoshHashanah, 2023),
+            Some(date!(2023 - 09 - 16))
+        );
+        assert_eq!(jewish_holiday(YomKippur, 2024), Some(date!(2024 - 10 - 12)));
+        assert_eq!(jewish_holiday(Sukkot, 2024), Some(date!(2024 - 10 - 17)));
+        assert_eq!(
+            jewish_holiday(SimchatTorah, 2024),
+            Some(date!(2024 - 10 - 24))
+        );
+        assert_eq!(jewish_holiday(Hanukkah, 2024), Some(date!(2024 - 12 - 26)));
+        assert_eq!(jewish_holiday(Purim, 2025), Some(date!(2025 - 03 - 14)));
+        assert_eq!(jewish_holiday(Pesach, 2024), Some(date!(2024 - 04 - 23)));
+        assert_eq!(
+            jewish_holiday(SeventhDayOfPesach, 2024),
+            Some(date!(2024 - 04 - 29))
+        );
+        assert_eq!(jewish_holiday(Shavuot, 2024), Some(date!(2024 - 06 - 12)));
+
+        // Independence Day: on 5 Iyar, postponed from Monday, brought forward from Saturday
+        assert_eq!(
+            jewish_holiday(YomHaatzmaut, 2023),
+            Some(date!(2023 - 04 - 26))
+        );
+        assert_eq!(
+            jewish_holiday(YomHaatzmaut, 2024),
+            Some(date!(2024 - 05 - 14))
+        );
+        assert_eq!(
+            jewish_holiday(YomHaatzmaut, 2025),
+            Some(date!(2025 - 05 - 01))
+        );
+
+        // Tisha B'Av, postponed from the Sabbath in 2025
+        assert_eq!(jewish_holiday(TishaBav, 2024), Some(date!(2024 - 08 - 13)));
+        assert_eq!(jewish_holiday(TishaBav, 2025), Some(date!(2025 - 08 - 03)));
+    }
+}
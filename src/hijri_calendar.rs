@@ -0,0 +1,520 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! This module defines the Hijri (Islamic) calendar, in its tabular and
+//! Umm al-Qura forms, and the dates of the Islamic holidays.
+
+use time::macros::date;
+use time::{Date, Duration};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Variants of the Hijri calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HijriCalendar {
+    /// Tabular (arithmetic) calendar: months of 30 and 29 days in turn,
+    /// with 11 leap years in a 30 year cycle, counted from the civil epoch (16 July 622).
+    Tabular,
+
+    /// Umm al-Qura calendar of Saudi Arabia, for the Hijri years 1420 - 1500.
+    UmmAlQura,
+}
+
+/// A date in the Hijri calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HijriDate {
+    /// Hijri year (AH).
+    pub year: i32,
+
+    /// Hijri month (1 - 12, Muharram to Dhu al-Hijjah).
+    pub month: u8,
+
+    /// Day of the Hijri month (1 - 30).
+    pub day: u8,
+}
+
+/// Islamic holidays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IslamicHoliday {
+    /// Islamic New Year (1 Muharram).
+    IslamicNewYear,
+
+    /// Day of Ashura (10 Muharram).
+    Ashura,
+
+    /// Birth of the Prophet Muhammad (12 Rabi' al-Awwal).
+    MawlidAnNabi,
+
+    /// Ascension of the Prophet Muhammad (27 Rajab).
+    IsraMiraj,
+
+    /// First day of Ramadan (1 Ramadan).
+    StartOfRamadan,
+
+    /// Eid al-Fitr (1 Shawwal).
+    EidAlFitr,
+
+    /// Day of Arafah (9 Dhu al-Hijjah).
+    DayOfArafah,
+
+    /// Eid al-Adha (10 Dhu al-Hijjah).
+    EidAlAdha,
+}
+
+/// Observance of the Islamic holidays in a country.
+///
+/// Most countries fix the holidays by sighting of the new moon,
+/// so their dates can differ from the computed calendar by a day or so.
+/// The `adjustment` (in days) is added to every computed date,
+/// and the `overrides` are the officially announced dates which differ from
+/// the adjusted ones. An override replaces the computed date of the same
+/// holiday when they are at most 3 days apart.
+#[derive(Debug, Clone, Copy)]
+pub struct IslamicObservance {
+    /// Hijri calendar used to compute the holidays.
+    pub calendar: HijriCalendar,
+
+    /// Number of days added to the computed dates.
+    pub adjustment: i64,
+
+    /// Officially announced dates, replacing the computed ones.
+    pub overrides: &'static [(IslamicHoliday, Date)],
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Julian day number of 1 Muharram 1 AH (16 July 622, Julian calendar).
+const TABULAR_EPOCH: i32 = 1_948_440;
+
+/// First Hijri year covered by the Umm al-Qura calendar.
+pub const UMM_AL_QURA_MIN_YEAR: i32 = 1420;
+
+/// Last Hijri year covered by the Umm al-Qura calendar.
+pub const UMM_AL_QURA_MAX_YEAR: i32 = 1500;
+
+/// Gregorian date of 1 Muharram 1420 in the Umm al-Qura calendar.
+const UMM_AL_QURA_EPOCH: Date = date!(1999 - 04 - 17);
+
+/// Month lengths of the Umm al-Qura calendar, for the Hijri years 1420 - 1500:
+/// bit `n - 1` is set if month `n` has 30 days (29 otherwise).
+///
+/// A month starts the day after its predecessor's 29th if, on the evening of
+/// the 29th at Mecca, the moon sets after the sun (and, since 1423, the
+/// conjunction takes place before sunset). It starts the day after the 30th otherwise.
+#[rustfmt::skip]
+const UMM_AL_QURA_INFO: [u16; 81] = [
+    0xbd2, 0xbc4, 0xb89, 0xa95, 0x52d, 0x5ad, 0xb6a, 0x6e4, 0xdc9, 0xd92, // 1420 - 1429
+    0xaa6, 0x956, 0x2ae, 0x56d, 0x36a, 0xb55, 0xaaa, 0x94d, 0x49d, 0x95d, // 1430 - 1439
+    0x2ba, 0x5b5, 0x5aa, 0xd55, 0xa9a, 0x92e, 0x25e, 0x55d, 0xada, 0x6d4, // 1440 - 1449
+    0x6a5, 0x54b, 0xa97, 0x54e, 0xaae, 0x5ac, 0xba9, 0xd92, 0xb25, 0x64b, // 1450 - 1459
+    0xcab, 0x55a, 0xb55, 0x6d2, 0xea5, 0xe4a, 0xa95, 0x52d, 0xaad, 0x36c, // 1460 - 1469
+    0x759, 0x6d2, 0x695, 0x52d, 0xa5b, 0x4ba, 0x9ba, 0x3b4, 0xb69, 0xb52, // 1470 - 1479
+    0xaa6, 0x4b6, 0x96d, 0x2ec, 0x6d9, 0xdb2, 0xd54, 0xd2a, 0xa56, 0x4ae, // 1480 - 1489
+    0x96d, 0xd6a, 0xb54, 0xb29, 0xa93, 0x52b, 0xa57, 0x536, 0xab5, 0x6aa, // 1490 - 1499
+    0xe93,                                                                // 1500 - 1500
+];
+
+/// Maximum distance (in days) between an override and the computed date it replaces.
+const MAX_OVERRIDE_DAYS: i64 = 3;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl HijriCalendar {
+    /// Function to get the number of days (29 or 30) in a Hijri month.
+    pub fn month_days(&self, year: i32, month: u8) -> Option<u8> {
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+
+        let is_long = match self {
+            HijriCalendar::Tabular => {
+                if year < 1 {
+                    return None;
+                }
+
+                month % 2 == 1 || (month == 12 && is_tabular_leap_year(year))
+            }
+            HijriCalendar::UmmAlQura => umm_al_qura_info(year)? & (1 << (month - 1)) != 0,
+        };
+
+        Some(if is_long { 30 } else { 29 })
+    }
+
+    /// Function to get the Gregorian date of the first day of a Hijri month.
+    fn month_start(&self, year: i32, month: u8) -> Option<Date> {
+        self.month_days(year, month)?;
+
+        match self {
+            HijriCalendar::Tabular => {
+                let month_offset = (59 * (month as i32 - 1) + 1) / 2;
+                let leap_days = (3 + 11 * year) / 30;
+
+                Date::from_julian_day(TABULAR_EPOCH + (year - 1) * 354 + leap_days + month_offset)
+                    .ok()
+            }
+            HijriCalendar::UmmAlQura => {
+                let days: i64 = (UMM_AL_QURA_MIN_YEAR..year)
+                    .map(|year| umm_al_qura_year_days(year) as i64)
+                    .chain((1..month).map(|month| self.month_days(year, month).unwrap() as i64))
+                    .sum();
+
+                Some(UMM_AL_QURA_EPOCH + Duration::days(days))
+            }
+        }
+    }
+
+    /// Function to get the Gregorian date of an Islamic holiday, in a Hijri year.
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::hijri_calendar::{HijriCalendar, IslamicHoliday};
+    ///
+    /// let eid = HijriCalendar::UmmAlQura.holiday(IslamicHoliday::EidAlFitr, 1445);
+    ///
+    /// assert_eq!(eid, Some(date!(2024 - 04 - 10)));
+    /// ```
+    pub fn holiday(&self, holiday: IslamicHoliday, hijri_year: i32) -> Option<Date> {
+        let (month, day) = holiday.month_day();
+
+        HijriDate::new(*self, hijri_year, month, day)?.to_gregorian(*self)
+    }
+}
+
+impl HijriDate {
+    /// Create a new Hijri date, if it exists in the calendar.
+    pub fn new(calendar: HijriCalendar, year: i32, month: u8, day: u8) -> Option<Self> {
+        let days = calendar.month_days(year, month)?;
+
+        match (1..=days).contains(&day) {
+            true => Some(Self { year, month, day }),
+            false => None,
+        }
+    }
+
+    /// Convert a Gregorian date to a Hijri date.
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::hijri_calendar::{HijriCalendar, HijriDate};
+    ///
+    /// let hijri = HijriDate::from_gregorian(HijriCalendar::UmmAlQura, date!(2023 - 07 - 19)).unwrap();
+    ///
+    /// assert_eq!((hijri.year, hijri.month, hijri.day), (1445, 1, 1));
+    /// ```
+    pub fn from_gregorian(calendar: HijriCalendar, date: Date) -> Option<Self> {
+        let mut year = match calendar {
+            HijriCalendar::Tabular => {
+                let days = date.to_julian_day() - TABULAR_EPOCH;
+
+                if days < 0 {
+                    return None;
+                }
+
+                (30 * days + 10646) / 10631
+            }
+            HijriCalendar::UmmAlQura => {
+                if date < UMM_AL_QURA_EPOCH {
+                    return None;
+                }
+
+                UMM_AL_QURA_MIN_YEAR
+            }
+        };
+
+        while calendar
+            .month_start(year + 1, 1)
+            .is_some_and(|start| start <= date)
+        {
+            year += 1;
+        }
+
+        for month in 1..=12 {
+            let start = calendar.month_start(year, month)?;
+            let days = calendar.month_days(year, month)? as i64;
+            let offset = (date - start).whole_days();
+
+            if offset < days {
+                return Some(Self {
+                    year,
+                    month,
+                    day: offset as u8 + 1,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Convert the Hijri date to a Gregorian date (`None` outside the calendar's range).
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::hijri_calendar::{HijriCalendar, HijriDate};
+    ///
+    /// // 1 Ramadan 1446
+    /// let hijri = HijriDate::new(HijriCalendar::UmmAlQura, 1446, 9, 1).unwrap();
+    ///
+    /// assert_eq!(hijri.to_gregorian(HijriCalendar::UmmAlQura), Some(date!(2025 - 03 - 01)));
+    /// ```
+    pub fn to_gregorian(&self, calendar: HijriCalendar) -> Option<Date> {
+        let days = calendar.month_days(self.year, self.month)?;
+
+        if !(1..=days).contains(&self.day) {
+            return None;
+        }
+
+        calendar
+            .month_start(self.year, self.month)
+            .map(|start| start + Duration::days(self.day as i64 - 1))
+    }
+}
+
+impl IslamicHoliday {
+    /// Function to get the (month, day) of the holiday in the Hijri calendar.
+    pub fn month_day(&self) -> (u8, u8) {
+        match self {
+            IslamicHoliday::IslamicNewYear => (1, 1),
+            IslamicHoliday::Ashura => (1, 10),
+            IslamicHoliday::MawlidAnNabi => (3, 12),
+            IslamicHoliday::IsraMiraj => (7, 27),
+            IslamicHoliday::StartOfRamadan => (9, 1),
+            IslamicHoliday::EidAlFitr => (10, 1),
+            IslamicHoliday::DayOfArafah => (12, 9),
+            IslamicHoliday::EidAlAdha => (12, 10),
+        }
+    }
+}
+
+impl IslamicObservance {
+    /// Create a new observance of the Islamic holidays.
+    pub const fn new(
+        calendar: HijriCalendar,
+        adjustment: i64,
+        overrides: &'static [(IslamicHoliday, Date)],
+    ) -> Self {
+        Self {
+            calendar,
+            adjustment,
+            overrides,
+        }
+    }
+
+    /// Function to get the observed date of an Islamic holiday, in a Hijri year.
+    pub fn holiday(&self, holiday: IslamicHoliday, hijri_year: i32) -> Option<Date> {
+        let date = self.calendar.holiday(holiday, hijri_year)? + Duration::days(self.adjustment);
+
+        let announced = self.overrides.iter().find(|(h, announced)| {
+            *h == holiday && (*announced - date).whole_days().abs() <= MAX_OVERRIDE_DAYS
+        });
+
+        Some(announced.map_or(date, |(_, announced)| *announced))
+    }
+
+    /// Function to get the observed dates of an Islamic holiday in a Gregorian year
+    /// (there can be two, as the Hijri year is 11 days shorter).
+    pub fn holiday_dates(&self, holiday: IslamicHoliday, year: i32) -> Vec<Date> {
+        let Ok(new_years_day) = Date::from_ordinal_date(year, 1) else {
+            return vec![];
+        };
+        let Some(hijri) = HijriDate::from_gregorian(HijriCalendar::Tabular, new_years_day) else {
+            return vec![];
+        };
+
+        (hijri.year - 1..=hijri.year + 2)
+            .filter_map(|hijri_year| self.holiday(holiday, hijri_year))
+            .filter(|date| date.year() == year)
+            .collect()
+    }
+
+    /// Check if the date is the observed date of an Islamic holiday.
+    pub fn is_holiday(&self, holiday: IslamicHoliday, date: Date) -> bool {
+        let Some(hijri) = HijriDate::from_gregorian(HijriCalendar::Tabular, date) else {
+            return false;
+        };
+
+        (hijri.year - 1..=hijri.year + 1)
+            .any(|hijri_year| self.holiday(holiday, hijri_year) == Some(date))
+    }
+}
+
+/// Check if a year of the tabular Hijri calendar is a leap year
+/// (years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29 of the 30 year cycle).
+pub fn is_tabular_leap_year(year: i32) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+/// Function to get the number of days in a year of the Umm al-Qura calendar.
+fn umm_al_qura_year_days(year: i32) -> u16 {
+    12 * 29 + umm_al_qura_info(year).unwrap().count_ones() as u16
+}
+
+/// Function to get the month lengths of a year of the Umm al-Qura calendar.
+fn umm_al_qura_info(year: i32) -> Option<u16> {
+    match (UMM_AL_QURA_MIN_YEAR..=UMM_AL_QURA_MAX_YEAR).contains(&year) {
+        true => Some(UMM_AL_QURA_INFO[(year - UMM_AL_QURA_MIN_YEAR) as usize]),
+        false => None,
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_hijri_calendar {
+    use super::*;
+    use crate::utilities::date_sequence;
+    use HijriCalendar::{Tabular, UmmAlQura};
+    use IslamicHoliday::*;
+
+    #[test]
+    fn test_tabular() {
+        assert_eq!(
+            HijriDate::new(Tabular, 1, 1, 1)
+                .unwrap()
+                .to_gregorian(Tabular),
+            Some(date!(0622 - 07 - 19))
+        );
+        assert_eq!(
+            Tabular.holiday(IslamicNewYear, 1445),
+            Some(date!(2023 - 07 - 19))
+        );
+        assert_eq!(
+            Tabular.holiday(EidAlFitr, 1444),
+            Some(date!(2023 - 04 - 22))
+        );
+
+        assert!(is_tabular_leap_year(1445));
+        assert!(!is_tabular_leap_year(1446));
+        assert_eq!(Tabular.month_days(1445, 12), Some(30));
+        assert_eq!(Tabular.month_days(1446, 12), Some(29));
+        assert_eq!(Tabular.month_days(1446, 13), None);
+        assert_eq!(HijriDate::new(Tabular, 1446, 2, 30), None);
+    }
+
+    #[test]
+    fn test_umm_al_qura() {
+        assert_eq!(
+            UmmAlQura.holiday(IslamicNewYear, 1421),
+            Some(date!(2000 - 04 - 06))
+        );
+        assert_eq!(
+            UmmAlQura.holiday(IslamicNewYear, 1445),
+            Some(date!(2023 - 07 - 19))
+        );
+        assert_eq!(
+            UmmAlQura.holiday(IslamicNewYear, 1446),
+            Some(date!(2024 - 07 - 07))
+        );
+        assert_eq!(
+            UmmAlQura.holiday(StartOfRamadan, 1444),
+            Some(date!(2023 - 03 - 23))
+        );
+        assert_eq!(
+            UmmAlQura.holiday(EidAlFitr, 1420),
+            Some(date!(2000 - 01 - 08))
+        );
+        assert_eq!(
+            UmmAlQura.holiday(EidAlFitr, 1444),
+            Some(date!(2023 - 04 - 21))
+        );
+        assert_eq!(
+            UmmAlQura.holiday(EidAlFitr, 1445),
+            Some(date!(2024 - 04 - 10))
+        );
+        assert_eq!(
+            UmmAlQura.holiday(EidAlFitr, 1446),
+            Some(date!(2025 - 03 - 30))
+        );
+        assert_eq!(
+            UmmAlQura.holiday(EidAlAdha, 1444),
+            Some(date!(2023 - 06 - 28))
+        );
+        assert_eq!(
+            UmmAlQura.holiday(EidAlAdha, 1445),
+            Some(date!(2024 - 06 - 16))
+        );
+
+        assert_eq!(UmmAlQura.holiday(EidAlFitr, 1419), None);
+        assert_eq!(UmmAlQura.holiday(EidAlFitr, 1501), None);
+        assert_eq!(
+            HijriDate::from_gregorian(UmmAlQura, date!(1999 - 04 - 16)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for calendar in [Tabular, UmmAlQura] {
+            let first = HijriDate::new(calendar, 1420, 1, 1)
+                .unwrap()
+                .to_gregorian(calendar)
+                .unwrap();
+            let last_day = calendar.month_days(1500, 12).unwrap();
+            let last = HijriDate::new(calendar, 1500, 12, last_day)
+                .unwrap()
+                .to_gregorian(calendar)
+                .unwrap();
+
+            for date in date_sequence(first, last) {
+                let hijri = HijriDate::from_gregorian(calendar, date).unwrap();
+                assert_eq!(hijri.to_gregorian(calendar), Some(date));
+            }
+        }
+
+        assert_eq!(
+            HijriDate::from_gregorian(UmmAlQura, date!(2077 - 10 - 18)),
+            HijriDate::new(UmmAlQura, 1500, 12, 1)
+        );
+        assert_eq!(
+            HijriDate::from_gregorian(UmmAlQura, date!(2077 - 11 - 17)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_observance() {
+        static OVERRIDES: [(IslamicHoliday, Date); 1] = [(EidAlFitr, date!(2024 - 04 - 11))];
+        let observance = IslamicObservance::new(UmmAlQura, 1, &OVERRIDES);
+
+        // Adjusted by a day
+        assert_eq!(
+            observance.holiday(EidAlAdha, 1445),
+            Some(date!(2024 - 06 - 17))
+        );
+
+        // Overridden by the announced date
+        assert_eq!(
+            observance.holiday(EidAlFitr, 1445),
+            Some(date!(2024 - 04 - 11))
+        );
+        assert!(observance.is_holiday(EidAlFitr, date!(2024 - 04 - 11)));
+        assert!(!observance.is_holiday(EidAlFitr, date!(2024 - 04 - 10)));
+        assert!(observance.is_holiday(EidAlFitr, date!(2023 - 04 - 22)));
+
+        // Islamic New Year falls twice in 2008
+        let observance = IslamicObservance::new(Tabular, 0, &[]);
+        assert_eq!(
+            observance.holiday_dates(IslamicNewYear, 2008),
+            vec![date!(2008 - 01 - 10), date!(2008 - 12 - 29)]
+        );
+        assert_eq!(
+            observance.holiday_dates(IslamicNewYear, 2009),
+            vec![date!(2009 - 12 - 18)]
+        );
+    }
+}
@@ -0,0 +1,298 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! This module defines the Hindu lunisolar calendar (panchang),
+//! and the dates of the main Hindu festivals, for the years 1900 - 2100.
+//!
+//! Lunar months are amanta (new moon to new moon), named after the sidereal
+//! sign (Lahiri ayanamsa) the Sun is in at the new moon which starts them.
+//! A month with no change of sign is a leap (adhika) month, and the festivals
+//! fall in the regular (nija) month of the same name.
+//!
+//! A festival falls on the day its tithi prevails at the time of day prescribed
+//! for it (sunrise, midday, afternoon, sunset or midnight), at New Delhi.
+
+use crate::astronomy::{from_julian_day, lunar_phase, lunation, new_moon, sun_longitude};
+use crate::astronomy::{sunrise, sunset, J2000, SYNODIC_MONTH};
+use time::{Date, Duration};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Months of the Hindu lunisolar calendar (amanta).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HinduMonth {
+    /// Chaitra (March - April).
+    Chaitra,
+    /// Vaishakha (April - May).
+    Vaishakha,
+    /// Jyeshtha (May - June).
+    Jyeshtha,
+    /// Ashadha (June - July).
+    Ashadha,
+    /// Shravana (July - August).
+    Shravana,
+    /// Bhadrapada (August - September).
+    Bhadrapada,
+    /// Ashvin (September - October).
+    Ashvin,
+    /// Kartika (October - November).
+    Kartika,
+    /// Margashirsha (November - December).
+    Margashirsha,
+    /// Pausha (December - January).
+    Pausha,
+    /// Magha (January - February).
+    Magha,
+    /// Phalguna (February - March).
+    Phalguna,
+}
+
+/// Time of day at which the tithi of a festival must prevail.
+#[derive(Debug, Clone, Copy)]
+enum Kala {
+    /// Sunrise (udaya).
+    Sunrise,
+    /// Midday (madhyahna), halfway between sunrise and sunset.
+    Midday,
+    /// Afternoon (aparahna), the fourth fifth of the daytime.
+    Afternoon,
+    /// Sunset (pradosha).
+    Sunset,
+    /// Midnight (nishita), halfway between sunset and the next sunrise.
+    Midnight,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// First year covered by the calendar.
+pub const HINDU_MIN_YEAR: i32 = 1900;
+
+/// Last year covered by the calendar.
+pub const HINDU_MAX_YEAR: i32 = 2100;
+
+/// Latitude of New Delhi (degrees).
+const LATITUDE: f64 = 28.6139;
+
+/// Longitude of New Delhi (degrees).
+const LONGITUDE: f64 = 77.2090;
+
+/// Indian Standard Time (UTC+05:30), in days.
+const IST: f64 = 5.5 / 24.0;
+
+/// Mean daily motion of the Moon away from the Sun (degrees).
+const LUNAR_PHASE_RATE: f64 = 360.0 / SYNODIC_MONTH;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to get the tithi (lunar day, 1 - 30) at a Julian day (UT).
+///
+/// Tithis 1 - 15 are the bright half (shukla paksha) of the month, ending with the
+/// full moon (purnima), and tithis 16 - 30 the dark half (krishna paksha),
+/// ending with the new moon (amavasya).
+pub fn tithi(jd: f64) -> u8 {
+    (lunar_phase(jd) / 12.0).floor() as u8 + 1
+}
+
+/// Function to get the Lahiri (Chitrapaksha) ayanamsa (degrees), the difference
+/// between the tropical and the sidereal longitudes.
+pub fn lahiri_ayanamsa(jd: f64) -> f64 {
+    23.857_092 + 1.396_888 * (jd - J2000) / 36_525.0
+}
+
+/// Function to get the sidereal sign (rashi, 0 - 11, from Mesha) of the Sun.
+fn rashi(jd: f64) -> i32 {
+    ((sun_longitude(jd) - lahiri_ayanamsa(jd)).rem_euclid(360.0) / 30.0).floor() as i32
+}
+
+/// Function to get the Indian (IST) date of a Julian day (UT).
+fn indian_date(jd: f64) -> Date {
+    from_julian_day(jd + IST).date()
+}
+
+/// Function to get the Julian days of the new moons starting and ending
+/// the regular (nija) lunar month which starts in a Gregorian year.
+fn lunar_month(year: i32, month: HinduMonth) -> Option<(f64, f64)> {
+    if !(HINDU_MIN_YEAR..=HINDU_MAX_YEAR).contains(&year) {
+        return None;
+    }
+
+    let new_years_day = Date::from_ordinal_date(year, 1).ok()?;
+    let first = lunation(new_years_day.to_julian_day() as f64 - 0.5 - IST);
+
+    (first..first + 14).find_map(|k| {
+        let (start, end) = (new_moon(k), new_moon(k + 1));
+
+        // Named after the sign at the start; no change of sign means a leap month
+        let is_leap = rashi(start) == rashi(end);
+        let is_month = (rashi(start) + 1) % 12 == month as i32;
+
+        (is_month && !is_leap && indian_date(start).year() == year).then_some((start, end))
+    })
+}
+
+/// Function to get the Julian day at which the lunar phase reaches an angle (degrees),
+/// starting from an estimate.
+fn lunar_phase_time(angle: f64, estimate: f64) -> f64 {
+    let mut jd = estimate;
+
+    for _ in 0..50 {
+        let difference = (lunar_phase(jd) - angle + 180.0).rem_euclid(360.0) - 180.0;
+        jd -= difference / LUNAR_PHASE_RATE;
+
+        if difference.abs() < 1e-5 {
+            break;
+        }
+    }
+
+    jd
+}
+
+/// Function to get the Julian day of the time of day `kala` on a date, at New Delhi.
+fn kala_time(date: Date, kala: Kala) -> f64 {
+    let rise = sunrise(date, LATITUDE, LONGITUDE).unwrap();
+    let set = sunset(date, LATITUDE, LONGITUDE).unwrap();
+
+    match kala {
+        Kala::Sunrise => rise,
+        Kala::Midday => rise + 0.5 * (set - rise),
+        Kala::Afternoon => rise + 0.7 * (set - rise),
+        Kala::Sunset => set,
+        Kala::Midnight => {
+            let next_rise = sunrise(date + Duration::days(1), LATITUDE, LONGITUDE).unwrap();
+            set + 0.5 * (next_rise - set)
+        }
+    }
+}
+
+/// Function to get the date of a festival: the first day on which the tithi of the month
+/// prevails at the time of day `kala`, or the day the tithi starts if it never does.
+fn hindu_festival(year: i32, month: HinduMonth, tithi: u8, kala: Kala) -> Option<Date> {
+    let (month_start, month_end) = lunar_month(year, month)?;
+    let estimate = |tithi: u8| month_start + (tithi - 1) as f64 * SYNODIC_MONTH / 30.0;
+
+    let start = match tithi {
+        1 => month_start,
+        _ => lunar_phase_time((tithi - 1) as f64 * 12.0, estimate(tithi)),
+    };
+    let end = match tithi {
+        30 => month_end,
+        _ => lunar_phase_time(tithi as f64 * 12.0, estimate(tithi + 1)),
+    };
+
+    let first_day = indian_date(start);
+
+    (0..3)
+        .map(|days| first_day + Duration::days(days))
+        .find(|date| (start..end).contains(&kala_time(*date, kala)))
+        .or(Some(first_day))
+}
+
+/// Maha Shivaratri: the 14th tithi of the dark half of Magha, at midnight.
+///
+/// ```
+/// use time::macros::date;
+/// use calenda_rs::hindu_calendar::maha_shivaratri;
+///
+/// assert_eq!(maha_shivaratri(2024), Some(date!(2024 - 03 - 08)));
+/// ```
+pub fn maha_shivaratri(year: i32) -> Option<Date> {
+    hindu_festival(year, HinduMonth::Magha, 29, Kala::Midnight)
+}
+
+/// Holi (Dhulandi): the day after Holika Dahan, which falls on the full moon
+/// of Phalguna, at sunset.
+pub fn holi(year: i32) -> Option<Date> {
+    hindu_festival(year, HinduMonth::Phalguna, 15, Kala::Sunset)
+        .map(|date| date + Duration::days(1))
+}
+
+/// Rama Navami: the 9th tithi of the bright half of Chaitra, at midday.
+pub fn rama_navami(year: i32) -> Option<Date> {
+    hindu_festival(year, HinduMonth::Chaitra, 9, Kala::Midday)
+}
+
+/// Mahavir Jayanti: the 13th tithi of the bright half of Chaitra, at sunrise.
+pub fn mahavir_jayanti(year: i32) -> Option<Date> {
+    hindu_festival(year, HinduMonth::Chaitra, 13, Kala::Sunrise)
+}
+
+/// Dussehra (Vijayadashami): the 10th tithi of the bright half of Ashvin, in the afternoon.
+pub fn dussehra(year: i32) -> Option<Date> {
+    hindu_festival(year, HinduMonth::Ashvin, 10, Kala::Afternoon)
+}
+
+/// Diwali (Lakshmi Puja): the new moon ending Ashvin (amanta), at sunset.
+///
+/// ```
+/// use time::macros::date;
+/// use calenda_rs::hindu_calendar::diwali;
+///
+/// assert_eq!(diwali(2024), Some(date!(2024 - 10 - 31)));
+/// ```
+pub fn diwali(year: i32) -> Option<Date> {
+    hindu_festival(year, HinduMonth::Ashvin, 30, Kala::Sunset)
+}
+
+/// Naraka Chaturdashi: the 14th tithi of the dark half of Ashvin (amanta), at sunrise.
+/// Deepavali is observed on this day in South India, Singapore and Malaysia.
+pub fn naraka_chaturdashi(year: i32) -> Option<Date> {
+    hindu_festival(year, HinduMonth::Ashvin, 29, Kala::Sunrise)
+}
+
+/// Guru Nanak Jayanti: the full moon of Kartika, at sunrise.
+pub fn guru_nanak_jayanti(year: i32) -> Option<Date> {
+    hindu_festival(year, HinduMonth::Kartika, 15, Kala::Sunrise)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_hindu_calendar {
+    use super::*;
+    use crate::astronomy::julian_day;
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn test_tithi() {
+        // Full moon of 2024-04-23 (23:49 UTC)
+        assert_eq!(tithi(julian_day(datetime!(2024-04-23 20:00 UTC))), 15);
+        assert_eq!(tithi(julian_day(datetime!(2024-04-24 04:00 UTC))), 16);
+
+        // New moon of 2024-04-08 (18:21 UTC)
+        assert_eq!(tithi(julian_day(datetime!(2024-04-08 18:00 UTC))), 30);
+        assert_eq!(tithi(julian_day(datetime!(2024-04-08 19:00 UTC))), 1);
+    }
+
+    #[test]
+    fn test_festivals() {
+        assert_eq!(maha_shivaratri(2024), Some(date!(2024 - 03 - 08)));
+        assert_eq!(holi(2024), Some(date!(2024 - 03 - 25)));
+        assert_eq!(rama_navami(2024), Some(date!(2024 - 04 - 17)));
+        assert_eq!(mahavir_jayanti(2024), Some(date!(2024 - 04 - 21)));
+        assert_eq!(dussehra(2024), Some(date!(2024 - 10 - 12)));
+        assert_eq!(diwali(2024), Some(date!(2024 - 10 - 31)));
+        assert_eq!(guru_nanak_jayanti(2024), Some(date!(2024 - 11 - 15)));
+
+        // Diwali in a year with a leap month (Adhika Shravana, 2023)
+        assert_eq!(diwali(2023), Some(date!(2023 - 11 - 12)));
+
+        assert_eq!(diwali(1899), None);
+        assert_eq!(diwali(2101), None);
+    }
+}
@@ -0,0 +1,185 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! This module defines the Korean lunisolar calendar (음력), and the dates of the
+//! lunar holidays, for the lunar years 1901 - 2150.
+//!
+//! The calendar follows the same rules as the Chinese calendar, but with the
+//! new moons and solar terms in Korea Standard Time (UTC+09:00), so that a few
+//! months start a day earlier than in China. It is computed from the astronomical
+//! new moons and solar terms:
+//! - a month starts on the day of a new moon;
+//! - the 11th month contains the December solstice;
+//! - in a year with 13 months between two 11th months, the first month without
+//!   a major solar term (a multiple of 30 degrees of solar longitude) is a leap month,
+//!   which repeats the number of the month before it.
+
+use crate::astronomy::{lunation, new_moon, solar_term, sun_longitude, SolarTerm};
+use time::Date;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// CONSTANTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// First lunar year covered by the calendar.
+pub const KOREAN_LUNAR_MIN_YEAR: i32 = 1901;
+
+/// Last lunar year covered by the calendar.
+pub const KOREAN_LUNAR_MAX_YEAR: i32 = 2150;
+
+/// Korea Standard Time (UTC+09:00), in days.
+const KST: f64 = 9.0 / 24.0;
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// FUNCTIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Function to get the Julian day number of the Korean date of a Julian day (UT).
+fn korean_day(jd: f64) -> i64 {
+    (jd + KST + 0.5).floor() as i64
+}
+
+/// Function to get the major solar term (0 - 11) in force at the start of a Korean day.
+fn major_term(day: i64) -> i64 {
+    (sun_longitude(day as f64 - 0.5 - KST) / 30.0).floor() as i64
+}
+
+/// Function to get the lunation number of the 11th month of the year ending
+/// with the December solstice of a Gregorian year.
+fn eleventh_month(year: i32) -> i32 {
+    let solstice = korean_day(solar_term(year, SolarTerm::DecemberSolstice));
+    let mut k = lunation(solar_term(year, SolarTerm::DecemberSolstice));
+
+    // The month starting on the (Korean) day of the solstice contains it
+    if korean_day(new_moon(k + 1)) <= solstice {
+        k += 1;
+    }
+
+    k
+}
+
+/// Function to list the lunar months from the 11th month of the year ending with the
+/// December solstice of a Gregorian year to the next 11th month (exclusive):
+/// (lunar year, month number, leap month, Julian day number of the first and the last day).
+fn lunar_months(year: i32) -> Vec<(i32, u8, bool, i64, i64)> {
+    let (first, last) = (eleventh_month(year - 1), eleventh_month(year));
+    let is_leap_year = last - first == 13;
+
+    let mut months = Vec::with_capacity(13);
+    let (mut lunar_year, mut number, mut leap_found) = (year - 1, 11, false);
+
+    for k in first..last {
+        let (start, end) = (korean_day(new_moon(k)), korean_day(new_moon(k + 1)));
+
+        // The first month (after the 11th) without a major solar term is the leap month
+        let is_leap =
+            k > first && is_leap_year && !leap_found && major_term(start) == major_term(end);
+
+        if is_leap {
+            leap_found = true;
+        } else if k > first {
+            number = number % 12 + 1;
+            if number == 1 {
+                lunar_year = year;
+            }
+        }
+
+        months.push((lunar_year, number, is_leap, start, end - 1));
+    }
+
+    months
+}
+
+/// Function to get the Gregorian date of a day of a (regular, not leap) Korean lunar month.
+///
+/// ```
+/// use time::macros::date;
+/// use calenda_rs::korean_calendar::korean_lunar_date;
+///
+/// // Seollal and Chuseok of 2024
+/// assert_eq!(korean_lunar_date(2024, 1, 1), Some(date!(2024 - 02 - 10)));
+/// assert_eq!(korean_lunar_date(2024, 8, 15), Some(date!(2024 - 09 - 17)));
+/// ```
+pub fn korean_lunar_date(year: i32, month: u8, day: u8) -> Option<Date> {
+    if !(KOREAN_LUNAR_MIN_YEAR..=KOREAN_LUNAR_MAX_YEAR).contains(&year) || day == 0 {
+        return None;
+    }
+
+    // The 11th and 12th months start the list of the following year
+    let months = match month {
+        11 | 12 => lunar_months(year + 1),
+        _ => lunar_months(year),
+    };
+
+    let &(_, _, _, start, end) = months
+        .iter()
+        .find(|&&(lunar_year, number, is_leap, _, _)| {
+            lunar_year == year && number == month && !is_leap
+        })?;
+    let date = start + day as i64 - 1;
+
+    match date <= end {
+        true => Date::from_julian_day(date as i32).ok(),
+        false => None,
+    }
+}
+
+/// Seollal (설날), the Korean New Year: the 1st day of the first lunar month.
+pub fn seollal(year: i32) -> Option<Date> {
+    korean_lunar_date(year, 1, 1)
+}
+
+/// Buddha's Birthday (부처님 오신 날): the 8th day of the fourth lunar month.
+pub fn korean_buddhas_birthday(year: i32) -> Option<Date> {
+    korean_lunar_date(year, 4, 8)
+}
+
+/// Chuseok (추석), the harvest festival: the 15th day of the eighth lunar month.
+pub fn chuseok(year: i32) -> Option<Date> {
+    korean_lunar_date(year, 8, 15)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_korean_calendar {
+    use super::*;
+    use time::macros::date;
+
+    // Test to verify the lunar holidays against the announced dates.
+    #[test]
+    fn test_lunar_holidays() {
+        assert_eq!(seollal(2023), Some(date!(2023 - 01 - 22)));
+        assert_eq!(seollal(2025), Some(date!(2025 - 01 - 29)));
+        assert_eq!(korean_buddhas_birthday(2024), Some(date!(2024 - 05 - 15)));
+        assert_eq!(chuseok(2025), Some(date!(2025 - 10 - 06)));
+
+        // A day later than in China, with the new moon before midnight in Korea
+        assert_eq!(seollal(1997), Some(date!(1997 - 02 - 08)));
+        assert_eq!(seollal(2027), Some(date!(2027 - 02 - 07)));
+        assert_eq!(korean_buddhas_birthday(2023), Some(date!(2023 - 05 - 27)));
+
+        // A different leap month than in China (3rd instead of 4th)
+        assert_eq!(korean_buddhas_birthday(2012), Some(date!(2012 - 05 - 28)));
+    }
+
+    // Test to verify the months at the end of the lunar year, and the range of the calendar.
+    #[test]
+    fn test_korean_lunar_date() {
+        assert_eq!(korean_lunar_date(2024, 12, 1), Some(date!(2024 - 12 - 31)));
+        assert_eq!(korean_lunar_date(2024, 12, 30), None);
+        assert_eq!(korean_lunar_date(2024, 1, 0), None);
+        assert_eq!(korean_lunar_date(1900, 1, 1), None);
+        assert_eq!(korean_lunar_date(2151, 1, 1), None);
+    }
+}
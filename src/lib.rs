@@ -14,10 +14,18 @@
 
 #![forbid(missing_docs)]
 
+/// Astronomical computations for the lunisolar calendars.
+pub mod astronomy;
+pub use astronomy::*;
+
 /// The core `Calendar` trait.
 pub mod calendar;
 pub use calendar::*;
 
+/// The Chinese lunisolar calendar.
+pub mod chinese_calendar;
+pub use chinese_calendar::*;
+
 /// Constants for calendars and holidays.
 #[allow(dead_code)]
 pub mod constants;
@@ -36,14 +44,38 @@ pub use date_rolling::*;
 pub mod day_counting;
 pub use day_counting::*;
 
+/// The Hebrew calendar.
+pub mod hebrew_calendar;
+pub use hebrew_calendar::*;
+
+/// The Hijri (Islamic) calendar.
+pub mod hijri_calendar;
+pub use hijri_calendar::*;
+
+/// The Hindu lunisolar calendar.
+pub mod hindu_calendar;
+pub use hindu_calendar::*;
+
+/// The Korean lunisolar calendar.
+pub mod korean_calendar;
+pub use korean_calendar::*;
+
 /// Frequency of payments.
 pub mod frequency;
 pub use frequency::*;
 
+/// The `Period` (tenor) type.
+pub mod period;
+pub use period::*;
+
 /// The `Holiday` trait.
 pub mod holiday;
 pub use holiday::*;
 
+/// The `Market` type: trading sessions of an exchange.
+pub mod market;
+pub use market::*;
+
 /// Utility functions for working with dates and times.
 pub mod utilities;
 pub use utilities::*;
@@ -51,3 +83,7 @@ pub use utilities::*;
 /// The `Schedule` type.
 pub mod schedule;
 pub use schedule::*;
+
+/// Time zones and their daylight saving time rules.
+pub mod time_zone;
+pub use time_zone::*;
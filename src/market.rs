@@ -0,0 +1,592 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! This module defines a `Market` type, combining a holiday calendar
+//! with the trading hours and time zone of an exchange.
+
+use crate::calendar::EarlyClose;
+use crate::constants::BUSINESS_DAILY;
+use crate::countries::asia::china::{SseCalendar, SzseCalendar};
+use crate::countries::asia::hong_kong::HkexCalendar;
+use crate::countries::asia::japan::JpxCalendar;
+use crate::countries::europe::united_kingdom::LondonExchangeCalendar;
+use crate::countries::north_america::united_states::NyseCalendar;
+use crate::time_zone::TimeZone;
+use time::macros::time;
+use time::{Date, Duration, OffsetDateTime, Time};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Regular trading hours of a market, in local time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradingHours {
+    /// Opening time.
+    pub open: Time,
+
+    /// Closing time.
+    pub close: Time,
+
+    /// Lunch break (start, end), if any.
+    pub lunch_break: Option<(Time, Time)>,
+}
+
+/// Trading session of a market on a given date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Session {
+    /// Local date of the session.
+    pub date: Date,
+
+    /// Opening instant.
+    pub open: OffsetDateTime,
+
+    /// Closing instant (the early close, if any).
+    pub close: OffsetDateTime,
+
+    /// Lunch break (start, end), if any.
+    pub lunch_break: Option<(OffsetDateTime, OffsetDateTime)>,
+
+    /// Whether the session closes early.
+    pub is_early_close: bool,
+
+    /// Whether the session opens late.
+    pub is_late_open: bool,
+}
+
+/// Number of days searched for the next session of a market, so that a market
+/// which never opens (e.g. with an opening time after its closing time) gives up.
+const SESSION_SEARCH_DAYS: usize = 5 * 366;
+
+/// Market (exchange) type.
+pub struct Market<C: EarlyClose> {
+    name: &'static str,
+    calendar: C,
+    time_zone: TimeZone,
+    trading_hours: TradingHours,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl TradingHours {
+    /// Create new trading hours without a lunch break.
+    pub const fn new(open: Time, close: Time) -> Self {
+        Self {
+            open,
+            close,
+            lunch_break: None,
+        }
+    }
+
+    /// Create new trading hours with a lunch break.
+    pub const fn with_lunch_break(
+        open: Time,
+        close: Time,
+        lunch_start: Time,
+        lunch_end: Time,
+    ) -> Self {
+        Self {
+            open,
+            close,
+            lunch_break: Some((lunch_start, lunch_end)),
+        }
+    }
+
+    /// Total trading time of a regular session, excluding the lunch break.
+    pub fn duration(&self) -> Duration {
+        let lunch = match self.lunch_break {
+            Some((start, end)) => end - start,
+            None => Duration::ZERO,
+        };
+
+        self.close - self.open - lunch
+    }
+}
+
+impl Session {
+    /// Trading intervals (open, close) of the session, split by the lunch break.
+    pub fn intervals(&self) -> Vec<(OffsetDateTime, OffsetDateTime)> {
+        match self.lunch_break {
+            Some((start, end)) => vec![(self.open, start), (end, self.close)],
+            None => vec![(self.open, self.close)],
+        }
+    }
+
+    /// Check if the market is open at the given instant during the session.
+    pub fn contains(&self, datetime: OffsetDateTime) -> bool {
+        self.intervals()
+            .iter()
+            .any(|(open, close)| *open <= datetime && datetime < *close)
+    }
+
+    /// Total trading time of the session, excluding the lunch break.
+    pub fn duration(&self) -> Duration {
+        self.intervals()
+            .iter()
+            .map(|(open, close)| *close - *open)
+            .sum()
+    }
+}
+
+impl<C: EarlyClose> Market<C> {
+    /// Create a new market.
+    pub fn new(
+        name: &'static str,
+        calendar: C,
+        time_zone: TimeZone,
+        trading_hours: TradingHours,
+    ) -> Self {
+        Self {
+            name,
+            calendar,
+            time_zone,
+            trading_hours,
+        }
+    }
+
+    /// Name of the market.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Holiday calendar of the market.
+    pub fn calendar(&self) -> &C {
+        &self.calendar
+    }
+
+    /// Time zone of the market.
+    pub fn time_zone(&self) -> TimeZone {
+        self.time_zone
+    }
+
+    /// Regular trading hours of the market.
+    pub fn trading_hours(&self) -> TradingHours {
+        self.trading_hours
+    }
+
+    /// Trading session on a given local date, or `None` if the market is closed.
+    ///
+    /// An early close during (or before) the lunch break ends the session
+    /// at the start of the lunch break, and a late open during (or after)
+    /// the lunch break starts the session at the end of the lunch break.
+    pub fn session(&self, date: Date) -> Option<Session> {
+        if !self.calendar.is_business_day(date) {
+            return None;
+        }
+
+        let hours = self.trading_hours;
+        let early_close = self
+            .calendar
+            .early_close(date)
+            .filter(|close| *close < hours.close);
+        let late_open = self
+            .calendar
+            .late_open(date)
+            .filter(|open| *open > hours.open);
+        let mut open = late_open.unwrap_or(hours.open);
+        let mut close = early_close.unwrap_or(hours.close);
+
+        let lunch_break = match hours.lunch_break {
+            Some((start, end)) if open < start && close >= end => Some((start, end)),
+            Some((start, end)) => {
+                // the session ends before, or starts after, the lunch break
+                if close < end {
+                    close = close.min(start);
+                } else {
+                    open = open.max(end);
+                }
+                None
+            }
+            None => None,
+        };
+
+        if open >= close {
+            return None;
+        }
+
+        let at = |time: Time| self.time_zone.from_local(date, time);
+
+        Some(Session {
+            date,
+            open: at(open),
+            close: at(close),
+            lunch_break: lunch_break.map(|(start, end)| (at(start), at(end))),
+            is_early_close: early_close.is_some(),
+            is_late_open: late_open.is_some(),
+        })
+    }
+
+    /// Check if the market is open at the given instant.
+    pub fn is_open(&self, datetime: OffsetDateTime) -> bool {
+        let date = self.time_zone.to_local(datetime).date();
+
+        self.session(date)
+            .is_some_and(|session| session.contains(datetime))
+    }
+
+    /// Next opening instant (including the end of a lunch break) after the given instant,
+    /// or `None` if the market has no session in the following five years.
+    pub fn next_open(&self, datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+        self.dates_from(datetime)
+            .take(SESSION_SEARCH_DAYS)
+            .filter_map(|date| self.session(date))
+            .flat_map(|session| session.intervals())
+            .map(|(open, _)| open)
+            .find(|open| *open > datetime)
+    }
+
+    /// Next closing instant (including the start of a lunch break) after the given instant,
+    /// or `None` if the market has no session in the following five years.
+    pub fn next_close(&self, datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+        self.dates_from(datetime)
+            .take(SESSION_SEARCH_DAYS)
+            .filter_map(|date| self.session(date))
+            .flat_map(|session| session.intervals())
+            .map(|(_, close)| close)
+            .find(|close| *close > datetime)
+    }
+
+    /// Trading time between two instants, over the sessions of the market.
+    ///
+    /// Holidays, weekends, lunch breaks and early closes are excluded.
+    /// The result is negative if `end` is before `start`.
+    pub fn trading_time_between(&self, start: OffsetDateTime, end: OffsetDateTime) -> Duration {
+        if end < start {
+            return -self.trading_time_between(end, start);
+        }
+
+        let last_date = self.time_zone.to_local(end).date();
+
+        self.dates_from(start)
+            .take_while(|date| *date <= last_date)
+            .filter_map(|date| self.session(date))
+            .flat_map(|session| session.intervals())
+            .map(|(open, close)| (close.min(end) - open.max(start)).max(Duration::ZERO))
+            .sum()
+    }
+
+    /// Number of (whole) trading minutes between two instants.
+    pub fn trading_minutes_between(&self, start: OffsetDateTime, end: OffsetDateTime) -> i64 {
+        self.trading_time_between(start, end).whole_minutes()
+    }
+
+    /// Year fraction of the trading time between two instants,
+    /// for a year of 252 regular sessions (e.g. 252 × 390 minutes for the NYSE).
+    ///
+    /// ```
+    /// use time::macros::datetime;
+    /// use calenda_rs::market::Market;
+    ///
+    /// let nyse = Market::nyse();
+    ///
+    /// // Friday 15:00 to Monday 10:30 (New York time): 60 + 60 trading minutes.
+    /// let start = datetime!(2024-07-12 15:00 -4);
+    /// let end = datetime!(2024-07-15 10:30 -4);
+    ///
+    /// assert_eq!(nyse.trading_minutes_between(start, end), 120);
+    /// assert_eq!(nyse.trading_year_fraction(start, end), 120.0 / (252.0 * 390.0));
+    /// ```
+    pub fn trading_year_fraction(&self, start: OffsetDateTime, end: OffsetDateTime) -> f64 {
+        let year = self.trading_hours.duration() * BUSINESS_DAILY as i32;
+
+        self.trading_time_between(start, end) / year
+    }
+
+    /// Function to iterate over the local dates, starting on the local date of the instant.
+    fn dates_from(&self, datetime: OffsetDateTime) -> impl Iterator<Item = Date> {
+        let start = self.time_zone.to_local(datetime).date();
+
+        std::iter::successors(Some(start), |date| date.next_day())
+    }
+}
+
+impl Market<NyseCalendar> {
+    /// New York Stock Exchange, 09:30 - 16:00 America/New_York.
+    pub fn nyse() -> Self {
+        Self::new(
+            "New York Stock Exchange",
+            NyseCalendar,
+            TimeZone::AmericaNewYork,
+            TradingHours::new(time!(09:30), time!(16:00)),
+        )
+    }
+}
+
+impl Market<LondonExchangeCalendar> {
+    /// London Stock Exchange, 08:00 - 16:30 Europe/London.
+    pub fn lse() -> Self {
+        Self::new(
+            "London Stock Exchange",
+            LondonExchangeCalendar::lse(),
+            TimeZone::EuropeLondon,
+            TradingHours::new(time!(08:00), time!(16:30)),
+        )
+    }
+}
+
+impl Market<HkexCalendar> {
+    /// Hong Kong Stock Exchange, 09:30 - 16:00 Asia/Hong_Kong,
+    /// with a lunch break from 12:00 to 13:00.
+    pub fn hkex() -> Self {
+        Self::new(
+            "Hong Kong Stock Exchange",
+            HkexCalendar::new(),
+            TimeZone::AsiaHongKong,
+            TradingHours::with_lunch_break(time!(09:30), time!(16:00), time!(12:00), time!(13:00)),
+        )
+    }
+}
+
+impl Market<SseCalendar> {
+    /// Shanghai Stock Exchange, 09:30 - 15:00 Asia/Shanghai,
+    /// with a lunch break from 11:30 to 13:00.
+    pub fn sse() -> Self {
+        Self::new(
+            "Shanghai Stock Exchange",
+            SseCalendar,
+            TimeZone::AsiaShanghai,
+            TradingHours::with_lunch_break(time!(09:30), time!(15:00), time!(11:30), time!(13:00)),
+        )
+    }
+}
+
+impl Market<SzseCalendar> {
+    /// Shenzhen Stock Exchange, 09:30 - 15:00 Asia/Shanghai,
+    /// with a lunch break from 11:30 to 13:00.
+    pub fn szse() -> Self {
+        Self::new(
+            "Shenzhen Stock Exchange",
+            SzseCalendar,
+            TimeZone::AsiaShanghai,
+            TradingHours::with_lunch_break(time!(09:30), time!(15:00), time!(11:30), time!(13:00)),
+        )
+    }
+}
+
+impl Market<JpxCalendar> {
+    /// Tokyo Stock Exchange, 09:00 - 15:30 Asia/Tokyo,
+    /// with a lunch break from 11:30 to 12:30.
+    pub fn tse() -> Self {
+        Self::new(
+            "Tokyo Stock Exchange",
+            JpxCalendar,
+            TimeZone::AsiaTokyo,
+            TradingHours::with_lunch_break(time!(09:00), time!(15:30), time!(11:30), time!(12:30)),
+        )
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_market {
+    use super::*;
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn test_nyse_session() {
+        let nyse = Market::nyse();
+
+        let session = nyse.session(date!(2024 - 07 - 15)).unwrap();
+        assert_eq!(session.open, datetime!(2024-07-15 13:30 UTC));
+        assert_eq!(session.close, datetime!(2024-07-15 20:00 UTC));
+        assert_eq!(session.duration(), Duration::minutes(390));
+
+        // early close on the day after Thanksgiving (standard time)
+        let session = nyse.session(date!(2024 - 11 - 29)).unwrap();
+        assert!(session.is_early_close);
+        assert_eq!(session.close, datetime!(2024-11-29 18:00 UTC));
+
+        // closed on Independence Day
+        assert!(nyse.session(date!(2024 - 07 - 04)).is_none());
+    }
+
+    #[test]
+    fn test_nyse_open_close() {
+        let nyse = Market::nyse();
+
+        assert!(nyse.is_open(datetime!(2024-07-15 13:30 UTC)));
+        assert!(!nyse.is_open(datetime!(2024-07-15 20:00 UTC)));
+        assert!(!nyse.is_open(datetime!(2024-07-13 15:00 UTC)));
+
+        // Friday evening before a weekend
+        let friday = datetime!(2024-07-12 21:00 UTC);
+        assert_eq!(
+            nyse.next_open(friday),
+            Some(datetime!(2024-07-15 13:30 UTC))
+        );
+        assert_eq!(
+            nyse.next_close(friday),
+            Some(datetime!(2024-07-15 20:00 UTC))
+        );
+
+        // July 3rd early close, then the Independence Day holiday
+        let july_3 = datetime!(2024-07-03 15:00 UTC);
+        assert_eq!(
+            nyse.next_close(july_3),
+            Some(datetime!(2024-07-03 17:00 UTC))
+        );
+        assert_eq!(
+            nyse.next_open(july_3),
+            Some(datetime!(2024-07-05 13:30 UTC))
+        );
+    }
+
+    #[test]
+    fn test_market_without_sessions() {
+        // Opening time after the closing time: the market never opens.
+        let market = Market::new(
+            "Closed",
+            NyseCalendar,
+            TimeZone::AmericaNewYork,
+            TradingHours::new(time!(16:00), time!(09:30)),
+        );
+        let (start, end) = (
+            datetime!(2024-07-15 13:30 UTC),
+            datetime!(2025-07-15 13:30 UTC),
+        );
+
+        assert_eq!(market.next_open(start), None);
+        assert_eq!(market.next_close(start), None);
+        assert_eq!(market.trading_time_between(start, end), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_hkex_lunch_break() {
+        let hkex = Market::hkex();
+
+        let session = hkex.session(date!(2024 - 07 - 15)).unwrap();
+        assert_eq!(session.duration(), Duration::minutes(330));
+
+        assert!(hkex.is_open(datetime!(2024-07-15 02:00 UTC)));
+        assert!(!hkex.is_open(datetime!(2024-07-15 04:30 UTC)));
+        assert_eq!(
+            hkex.next_open(datetime!(2024-07-15 04:30 UTC)),
+            Some(datetime!(2024-07-15 05:00 UTC))
+        );
+        assert_eq!(
+            hkex.next_close(datetime!(2024-07-15 02:00 UTC)),
+            Some(datetime!(2024-07-15 04:00 UTC))
+        );
+
+        // half day on Christmas Eve: morning session only
+        let session = hkex.session(date!(2024 - 12 - 24)).unwrap();
+        assert!(session.is_early_close);
+        assert_eq!(session.lunch_break, None);
+        assert_eq!(session.close, datetime!(2024-12-24 04:00 UTC));
+    }
+
+    #[test]
+    fn test_lse_daylight_saving() {
+        let lse = Market::lse();
+
+        assert_eq!(
+            lse.session(date!(2024 - 01 - 15)).unwrap().open,
+            datetime!(2024-01-15 08:00 UTC)
+        );
+        assert_eq!(
+            lse.session(date!(2024 - 07 - 15)).unwrap().open,
+            datetime!(2024-07-15 07:00 UTC)
+        );
+        assert_eq!(
+            lse.session(date!(2024 - 12 - 24)).unwrap().close,
+            datetime!(2024-12-24 12:30 UTC)
+        );
+    }
+
+    #[test]
+    fn test_sse_session() {
+        let sse = Market::sse();
+
+        let session = sse.session(date!(2023 - 03 - 15)).unwrap();
+        assert_eq!(session.duration(), Duration::minutes(240));
+        assert_eq!(session.intervals()[1].0, datetime!(2023-03-15 05:00 UTC));
+
+        // Closed on the adjusted working weekend days
+        assert!(sse.session(date!(2023 - 10 - 07)).is_none());
+        assert!(Market::szse().session(date!(2023 - 10 - 07)).is_none());
+        assert!(Market::szse().session(date!(2023 - 10 - 09)).is_some());
+    }
+
+    #[test]
+    fn test_tse_session() {
+        let tse = Market::tse();
+
+        let session = tse.session(date!(2025 - 01 - 06)).unwrap();
+        assert_eq!(session.duration(), Duration::minutes(330));
+        assert_eq!(session.open, datetime!(2025-01-06 00:00 UTC));
+
+        // year-end closure
+        assert!(tse.session(date!(2025 - 01 - 03)).is_none());
+    }
+
+    #[test]
+    fn test_trading_time_between() {
+        let nyse = Market::nyse();
+
+        // a full regular session
+        let start = datetime!(2024-07-15 00:00 UTC);
+        let end = datetime!(2024-07-16 00:00 UTC);
+        assert_eq!(nyse.trading_minutes_between(start, end), 390);
+        assert_eq!(nyse.trading_minutes_between(end, start), -390);
+
+        // a week with Independence Day and the July 3rd early close
+        let start = datetime!(2024-07-01 00:00 UTC);
+        let end = datetime!(2024-07-08 00:00 UTC);
+        assert_eq!(nyse.trading_minutes_between(start, end), 3 * 390 + 210);
+
+        // inside a session
+        let start = datetime!(2024-07-15 14:00 UTC);
+        let end = datetime!(2024-07-15 14:45:30 UTC);
+        assert_eq!(
+            nyse.trading_time_between(start, end),
+            Duration::seconds(45 * 60 + 30)
+        );
+        assert_eq!(nyse.trading_minutes_between(start, end), 45);
+    }
+
+    #[test]
+    fn test_trading_year_fraction() {
+        let nyse = Market::nyse();
+        let hkex = Market::hkex();
+
+        let start = datetime!(2024-07-15 00:00 UTC);
+        let end = datetime!(2024-07-16 00:00 UTC);
+        assert_eq!(nyse.trading_year_fraction(start, end), 1.0 / 252.0);
+
+        // lunch break excluded from both the session and the year
+        assert_eq!(hkex.trading_hours().duration(), Duration::minutes(330));
+        assert_eq!(hkex.trading_year_fraction(start, end), 1.0 / 252.0);
+
+        let start = datetime!(2024-07-15 03:00 UTC);
+        let end = datetime!(2024-07-15 06:00 UTC);
+        assert_eq!(hkex.trading_minutes_between(start, end), 120);
+    }
+
+    #[test]
+    fn test_hkex_weather_closures() {
+        let hkex = Market::hkex();
+
+        // afternoon session only (Koinu, 9 October 2023)
+        let session = hkex.session(date!(2023 - 10 - 09)).unwrap();
+        assert!(session.is_late_open);
+        assert_eq!(session.lunch_break, None);
+        assert_eq!(session.open, datetime!(2023-10-09 05:00 UTC));
+        assert_eq!(session.duration(), Duration::minutes(180));
+
+        // no trading (black rainstorm, 8 September 2023)
+        assert!(hkex.session(date!(2023 - 09 - 08)).is_none());
+    }
+}
@@ -0,0 +1,103 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! This module defines a `Period` (tenor) type and its methods.
+
+use std::fmt;
+use time::{Date, Duration, Month};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Units of time for a `Period`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    /// Calendar days.
+    Days,
+
+    /// Weeks (7 calendar days).
+    Weeks,
+
+    /// Calendar months.
+    Months,
+
+    /// Calendar years.
+    Years,
+}
+
+/// A period of time, or tenor, such as 3 months or 1 year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+    /// The number of time units in the period.
+    pub length: i32,
+
+    /// The time unit of the period.
+    pub unit: TimeUnit,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl Period {
+    /// Create a new period.
+    pub const fn new(length: i32, unit: TimeUnit) -> Self {
+        Self { length, unit }
+    }
+
+    /// Add the period to a date, a given number of times.
+    ///
+    /// Month and year periods keep the day of the month,
+    /// capped at the length of the resulting month.
+    ///
+    /// ```
+    /// use time::macros::date;
+    /// use calenda_rs::period::{Period, TimeUnit};
+    ///
+    /// let period = Period::new(1, TimeUnit::Months);
+    ///
+    /// assert_eq!(period.advance(date!(2024 - 01 - 31), 1), date!(2024 - 02 - 29));
+    /// assert_eq!(period.advance(date!(2024 - 01 - 31), -2), date!(2023 - 11 - 30));
+    /// ```
+    pub fn advance(&self, date: Date, times: i32) -> Date {
+        let length = self.length * times;
+
+        match self.unit {
+            TimeUnit::Days => date + Duration::days(length as i64),
+            TimeUnit::Weeks => date + Duration::weeks(length as i64),
+            TimeUnit::Months => Self::add_months(date, length),
+            TimeUnit::Years => Self::add_months(date, 12 * length),
+        }
+    }
+
+    /// Function to add a number of months to a date.
+    fn add_months(date: Date, months: i32) -> Date {
+        let total_months = date.year() * 12 + date.month() as i32 - 1 + months;
+        let year = total_months.div_euclid(12);
+        let month = Month::try_from((total_months.rem_euclid(12) + 1) as u8).unwrap();
+        let day = date.day().min(month.length(year));
+
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+}
+
+impl fmt::Display for Period {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            TimeUnit::Days      => write!(f, "{}D", self.length),
+            TimeUnit::Weeks     => write!(f, "{}W", self.length),
+            TimeUnit::Months    => write!(f, "{}M", self.length),
+            TimeUnit::Years     => write!(f, "{}Y", self.length),
+        }
+    }
+}
@@ -10,7 +10,7 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use crate::date_rolling::{DateRoller, DateRollingConvention};
-use crate::day_counting::{DayCountConvention, DayCounter};
+use crate::day_counting::{DayCountContext, DayCountConvention, DayCounter};
 use crate::Calendar;
 use std::fmt;
 use time::{Date, OffsetDateTime};
@@ -79,11 +79,26 @@ where
         let rolled_dates = self.roll_dates(dates, &date_rolling_convention);
 
         // Then we need to compute the day count factors.
-        let mut day_count_factors = self.day_count_factors(&rolled_dates, &day_counting_convention);
-        day_count_factors.insert(
-            0,
-            self.day_count_factor(today, rolled_dates[0], &day_counting_convention),
-        );
+        // The last date is the termination date of the schedule.
+        let context = DayCountContext {
+            termination_date: rolled_dates.last().copied(),
+            ..Default::default()
+        };
+
+        let mut period_dates = vec![today];
+        period_dates.extend(&rolled_dates);
+
+        let day_count_factors = period_dates
+            .windows(2)
+            .map(|window| {
+                self.day_count_factor_with_context(
+                    window[0],
+                    window[1],
+                    &day_counting_convention,
+                    &context,
+                )
+            })
+            .collect();
 
         Schedule {
             dates: rolled_dates,
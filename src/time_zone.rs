@@ -0,0 +1,411 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// calenda-rs: A Rust library for global calendars.
+// Copyright (C) 2024 https://github.com/avhz
+//
+// Dual licensed under Apache 2.0 and MIT.
+//
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! This module defines a `TimeZone` type, with bundled daylight saving time rules
+//! for the time zones of the supported markets.
+//!
+//! The bundled rules only cover the current (and recent) daylight saving time rules.
+//! With the `time-tz` feature, the offsets are taken from the IANA time zone database
+//! instead, for the full history of each time zone.
+
+#[cfg(not(feature = "time-tz"))]
+use crate::utilities::{get_first_sunday_of_month, get_last_sunday_of_month};
+#[cfg(not(feature = "time-tz"))]
+use time::{macros::time, Month};
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// STRUCTS, ENUMS, AND TRAITS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Daylight saving time rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaylightSavingRule {
+    /// No daylight saving time.
+    None,
+
+    /// United States: second Sunday of March to first Sunday of November (since 2007),
+    /// first Sunday of April to last Sunday of October (1987 - 2006),
+    /// last Sunday of April to last Sunday of October (1967 - 1986).
+    /// Transitions happen at 02:00 local time.
+    UnitedStates,
+
+    /// European Union: last Sunday of March to last Sunday of October,
+    /// at 01:00 UTC (since 1996, when the rules were harmonised;
+    /// earlier rules are not modelled).
+    EuropeanUnion,
+
+    /// South-eastern Australia: first Sunday of October to first Sunday of April
+    /// (since 2008, earlier rules are not modelled).
+    /// Transitions happen at 02:00 local standard time.
+    SouthEasternAustralia,
+}
+
+/// IANA time zones of the supported markets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeZone {
+    /// America/New_York (NYSE, Nasdaq).
+    AmericaNewYork,
+
+    /// America/Chicago (CME).
+    AmericaChicago,
+
+    /// Europe/London (LSE, LME).
+    EuropeLondon,
+
+    /// Europe/Berlin (Xetra, Eurex).
+    EuropeBerlin,
+
+    /// Asia/Hong_Kong (HKEX).
+    AsiaHongKong,
+
+    /// Asia/Shanghai (SSE, SZSE).
+    AsiaShanghai,
+
+    /// Asia/Tokyo (TSE).
+    AsiaTokyo,
+
+    /// Asia/Singapore (SGX).
+    AsiaSingapore,
+
+    /// Asia/Kolkata (NSE, BSE).
+    AsiaKolkata,
+
+    /// Australia/Sydney (ASX).
+    AustraliaSydney,
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// IMPLEMENTATIONS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl TimeZone {
+    /// IANA name of the time zone.
+    #[rustfmt::skip]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AmericaNewYork    => "America/New_York",
+            Self::AmericaChicago    => "America/Chicago",
+            Self::EuropeLondon      => "Europe/London",
+            Self::EuropeBerlin      => "Europe/Berlin",
+            Self::AsiaHongKong      => "Asia/Hong_Kong",
+            Self::AsiaShanghai      => "Asia/Shanghai",
+            Self::AsiaTokyo         => "Asia/Tokyo",
+            Self::AsiaSingapore     => "Asia/Singapore",
+            Self::AsiaKolkata       => "Asia/Kolkata",
+            Self::AustraliaSydney   => "Australia/Sydney",
+        }
+    }
+
+    /// Standard (winter) offset from UTC, in seconds.
+    #[rustfmt::skip]
+    fn standard_offset_seconds(&self) -> i32 {
+        match self {
+            Self::AmericaNewYork    => -5 * 3600,
+            Self::AmericaChicago    => -6 * 3600,
+            Self::EuropeLondon      => 0,
+            Self::EuropeBerlin      => 3600,
+            Self::AsiaHongKong      => 8 * 3600,
+            Self::AsiaShanghai      => 8 * 3600,
+            Self::AsiaTokyo         => 9 * 3600,
+            Self::AsiaSingapore     => 8 * 3600,
+            Self::AsiaKolkata       => 5 * 3600 + 1800,
+            Self::AustraliaSydney   => 10 * 3600,
+        }
+    }
+
+    /// Standard (winter) offset from UTC.
+    pub fn standard_offset(&self) -> UtcOffset {
+        UtcOffset::from_whole_seconds(self.standard_offset_seconds()).unwrap()
+    }
+
+    /// Daylight saving time rule of the time zone.
+    #[rustfmt::skip]
+    pub fn daylight_saving_rule(&self) -> DaylightSavingRule {
+        match self {
+            Self::AmericaNewYork    => DaylightSavingRule::UnitedStates,
+            Self::AmericaChicago    => DaylightSavingRule::UnitedStates,
+            Self::EuropeLondon      => DaylightSavingRule::EuropeanUnion,
+            Self::EuropeBerlin      => DaylightSavingRule::EuropeanUnion,
+            Self::AustraliaSydney   => DaylightSavingRule::SouthEasternAustralia,
+            _                       => DaylightSavingRule::None,
+        }
+    }
+
+    /// Check if daylight saving time is in effect at the given instant.
+    pub fn is_daylight_saving(&self, datetime: OffsetDateTime) -> bool {
+        self.offset_at(datetime).1
+    }
+
+    /// Offset from UTC at the given instant.
+    pub fn utc_offset(&self, datetime: OffsetDateTime) -> UtcOffset {
+        self.offset_at(datetime).0
+    }
+
+    /// Convert an instant to the local time of the time zone.
+    ///
+    /// ```
+    /// use time::macros::datetime;
+    /// use calenda_rs::time_zone::TimeZone;
+    ///
+    /// let new_york = TimeZone::AmericaNewYork;
+    ///
+    /// assert_eq!(new_york.to_local(datetime!(2024-01-15 14:30 UTC)).hour(), 9);
+    /// assert_eq!(new_york.to_local(datetime!(2024-07-15 14:30 UTC)).hour(), 10);
+    /// ```
+    pub fn to_local(&self, datetime: OffsetDateTime) -> OffsetDateTime {
+        datetime.to_offset(self.utc_offset(datetime))
+    }
+
+    /// Create an instant from a local date and time in the time zone.
+    ///
+    /// Local times skipped by a daylight saving transition are read with the
+    /// standard offset, and repeated local times resolve to the later instant.
+    pub fn from_local(&self, date: Date, time: Time) -> OffsetDateTime {
+        self.resolve_local(PrimitiveDateTime::new(date, time))
+    }
+
+    /// Offset from UTC at the given instant, and whether it is daylight saving time,
+    /// from the bundled rules.
+    #[cfg(not(feature = "time-tz"))]
+    fn offset_at(&self, datetime: OffsetDateTime) -> (UtcOffset, bool) {
+        let year = datetime.to_offset(UtcOffset::UTC).year();
+
+        let is_daylight_saving = match self.daylight_saving_transitions(year) {
+            None => false,
+            Some((start, end)) if start < end => start <= datetime && datetime < end,
+            // southern hemisphere: daylight saving time spans the new year
+            Some((start, end)) => datetime < end || start <= datetime,
+        };
+
+        match is_daylight_saving {
+            true => (
+                UtcOffset::from_whole_seconds(self.standard_offset_seconds() + 3600).unwrap(),
+                true,
+            ),
+            false => (self.standard_offset(), false),
+        }
+    }
+
+    /// Offset from UTC at the given instant, and whether it is daylight saving time,
+    /// from the IANA time zone database.
+    #[cfg(feature = "time-tz")]
+    fn offset_at(&self, datetime: OffsetDateTime) -> (UtcOffset, bool) {
+        use time_tz::{Offset, TimeZone};
+
+        let offset = self.tz().get_offset_utc(&datetime);
+
+        (offset.to_utc(), offset.is_dst())
+    }
+
+    /// IANA time zone database entry of the time zone.
+    #[cfg(feature = "time-tz")]
+    fn tz(&self) -> &'static time_tz::Tz {
+        time_tz::timezones::get_by_name(self.name()).unwrap()
+    }
+
+    /// Instant of a local date and time, from the IANA time zone database.
+    #[cfg(feature = "time-tz")]
+    fn resolve_local(&self, local: PrimitiveDateTime) -> OffsetDateTime {
+        use time_tz::{OffsetResult, PrimitiveDateTimeExt};
+
+        match local.assume_timezone(self.tz()) {
+            OffsetResult::Some(datetime) => datetime,
+            OffsetResult::Ambiguous(first, second) => first.max(second),
+            // skipped local time: read with the offset before the transition
+            OffsetResult::None => {
+                local.assume_offset(self.utc_offset(local.assume_utc() - Duration::DAY))
+            }
+        }
+    }
+
+    /// Instant of a local date and time, from the bundled rules.
+    #[cfg(not(feature = "time-tz"))]
+    fn resolve_local(&self, local: PrimitiveDateTime) -> OffsetDateTime {
+        let standard = local.assume_offset(self.standard_offset());
+
+        if self.is_daylight_saving(standard) {
+            let daylight = local.assume_offset(self.utc_offset(standard));
+
+            if self.is_daylight_saving(daylight) {
+                return daylight;
+            }
+        }
+
+        standard
+    }
+
+    /// Start and end instants of daylight saving time in the given (UTC) year.
+    #[cfg(not(feature = "time-tz"))]
+    fn daylight_saving_transitions(&self, year: i32) -> Option<(OffsetDateTime, OffsetDateTime)> {
+        let standard = self.standard_offset();
+        let daylight =
+            UtcOffset::from_whole_seconds(self.standard_offset_seconds() + 3600).unwrap();
+
+        let first_sunday = |month: Month| get_first_sunday_of_month(year, month).unwrap();
+        let last_sunday = |month: Month| get_last_sunday_of_month(year, month).unwrap();
+        let at = |date: Date, time: Time, offset: UtcOffset| {
+            PrimitiveDateTime::new(date, time).assume_offset(offset)
+        };
+
+        match self.daylight_saving_rule() {
+            DaylightSavingRule::None => None,
+            DaylightSavingRule::UnitedStates => {
+                let (start, end) = match year {
+                    2007.. => (
+                        first_sunday(Month::March) + Duration::weeks(1),
+                        first_sunday(Month::November),
+                    ),
+                    1987..=2006 => (first_sunday(Month::April), last_sunday(Month::October)),
+                    1967..=1986 => (last_sunday(Month::April), last_sunday(Month::October)),
+                    _ => return None,
+                };
+
+                Some((
+                    at(start, time!(02:00), standard),
+                    at(end, time!(02:00), daylight),
+                ))
+            }
+            DaylightSavingRule::EuropeanUnion if year >= 1996 => Some((
+                at(last_sunday(Month::March), time!(01:00), UtcOffset::UTC),
+                at(last_sunday(Month::October), time!(01:00), UtcOffset::UTC),
+            )),
+            DaylightSavingRule::EuropeanUnion => None,
+            DaylightSavingRule::SouthEasternAustralia => Some((
+                at(first_sunday(Month::October), time!(02:00), standard),
+                at(first_sunday(Month::April), time!(02:00), standard),
+            )),
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// UNIT TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod test_time_zone {
+    use super::*;
+    use time::macros::{date, datetime, offset, time};
+
+    #[test]
+    fn test_united_states_transitions() {
+        let tz = TimeZone::AmericaNewYork;
+
+        // 2024: March 10th to November 3rd
+        assert_eq!(tz.utc_offset(datetime!(2024-03-10 06:59 UTC)), offset!(-5));
+        assert_eq!(tz.utc_offset(datetime!(2024-03-10 07:00 UTC)), offset!(-4));
+        assert_eq!(tz.utc_offset(datetime!(2024-11-03 05:59 UTC)), offset!(-4));
+        assert_eq!(tz.utc_offset(datetime!(2024-11-03 06:00 UTC)), offset!(-5));
+
+        // 2006: April 2nd to October 29th
+        assert_eq!(tz.utc_offset(datetime!(2006-03-20 12:00 UTC)), offset!(-5));
+        assert_eq!(tz.utc_offset(datetime!(2006-04-03 12:00 UTC)), offset!(-4));
+        assert_eq!(tz.utc_offset(datetime!(2006-10-30 12:00 UTC)), offset!(-5));
+    }
+
+    #[test]
+    fn test_european_union_transitions() {
+        let london = TimeZone::EuropeLondon;
+        let berlin = TimeZone::EuropeBerlin;
+
+        // 2024: March 31st to October 27th, at 01:00 UTC
+        assert_eq!(
+            london.utc_offset(datetime!(2024-03-31 00:59 UTC)),
+            offset!(+0)
+        );
+        assert_eq!(
+            london.utc_offset(datetime!(2024-03-31 01:00 UTC)),
+            offset!(+1)
+        );
+        assert_eq!(
+            berlin.utc_offset(datetime!(2024-10-27 00:59 UTC)),
+            offset!(+2)
+        );
+        assert_eq!(
+            berlin.utc_offset(datetime!(2024-10-27 01:00 UTC)),
+            offset!(+1)
+        );
+    }
+
+    // The European Union rule only applies from 1996 (summer time ended in September before).
+    #[cfg(not(feature = "time-tz"))]
+    #[test]
+    fn test_european_union_before_1996() {
+        let berlin = TimeZone::EuropeBerlin;
+
+        assert!(!berlin.is_daylight_saving(datetime!(1995-10-15 12:00 UTC)));
+        assert!(berlin.is_daylight_saving(datetime!(1996-10-15 12:00 UTC)));
+    }
+
+    #[test]
+    fn test_southern_hemisphere_transitions() {
+        let sydney = TimeZone::AustraliaSydney;
+
+        assert_eq!(
+            sydney.utc_offset(datetime!(2024-01-15 00:00 UTC)),
+            offset!(+11)
+        );
+        assert_eq!(
+            sydney.utc_offset(datetime!(2024-07-15 00:00 UTC)),
+            offset!(+10)
+        );
+        assert_eq!(
+            sydney.utc_offset(datetime!(2024-12-15 00:00 UTC)),
+            offset!(+11)
+        );
+    }
+
+    // British Standard Time (1968 - 1971) is only in the IANA time zone database.
+    #[cfg(feature = "time-tz")]
+    #[test]
+    fn test_time_tz_history() {
+        let london = TimeZone::EuropeLondon;
+
+        assert_eq!(
+            london.utc_offset(datetime!(1970-01-15 12:00 UTC)),
+            offset!(+1)
+        );
+        assert_eq!(
+            london.from_local(date!(1970 - 01 - 15), time!(08:00)),
+            datetime!(1970-01-15 07:00 UTC)
+        );
+    }
+
+    #[test]
+    fn test_from_local() {
+        let new_york = TimeZone::AmericaNewYork;
+        let kolkata = TimeZone::AsiaKolkata;
+
+        assert_eq!(
+            new_york.from_local(date!(2024 - 01 - 15), time!(09:30)),
+            datetime!(2024-01-15 14:30 UTC)
+        );
+        assert_eq!(
+            new_york.from_local(date!(2024 - 07 - 15), time!(09:30)),
+            datetime!(2024-07-15 13:30 UTC)
+        );
+        assert_eq!(
+            kolkata.from_local(date!(2024 - 07 - 15), time!(09:15)),
+            datetime!(2024-07-15 03:45 UTC)
+        );
+
+        // skipped and repeated local times
+        assert_eq!(
+            new_york.from_local(date!(2024 - 03 - 10), time!(02:30)),
+            datetime!(2024-03-10 07:30 UTC)
+        );
+        assert_eq!(
+            new_york.from_local(date!(2024 - 11 - 03), time!(01:30)),
+            datetime!(2024-11-03 06:30 UTC)
+        );
+    }
+}
@@ -121,6 +121,25 @@ pub fn leap_year_count(start: Date, end: Date) -> i64 {
         .len() as i64
 }
 
+/// Function to count the leap days (29th February) in the period
+/// from `start` (exclusive) to `end` (inclusive).
+///
+/// ```
+/// use time::macros::date;
+/// use calenda_rs::utilities::leap_day_count;
+///
+/// assert_eq!(leap_day_count(date!(2023 - 07 - 01), date!(2025 - 01 - 01)), 1);
+/// assert_eq!(leap_day_count(date!(2024 - 02 - 29), date!(2025 - 01 - 01)), 0);
+/// ```
+pub fn leap_day_count(start: Date, end: Date) -> i64 {
+    get_years_in_range(start, end)
+        .iter()
+        .filter(|&&y| is_leap_year(y))
+        .map(|&y| Date::from_calendar_date(y, Month::February, 29).unwrap())
+        .filter(|&leap_day| start < leap_day && leap_day <= end)
+        .count() as i64
+}
+
 /// Function to check if date is the last day of February.
 pub fn is_last_day_of_february(date: Date) -> bool {
     let last_day_of_feb_non_leap =
@@ -153,6 +172,13 @@ pub fn previous_business_day<C: Calendar>(date: Date, calendar: &C) -> Date {
     new_date
 }
 
+/// Function to get the last calendar day of the month that a date falls in.
+pub fn last_day_of_month(date: Date) -> Date {
+    let days_in_month = date.month().length(date.year());
+
+    Date::from_calendar_date(date.year(), date.month(), days_in_month).unwrap()
+}
+
 /// Function to generate a sequence of dates from a start date, end date.
 pub fn date_sequence(start: Date, end: Date) -> Vec<Date> {
     let mut dates = Vec::with_capacity((end - start).whole_days() as usize);